@@ -0,0 +1,201 @@
+use quote::*;
+
+use crate::rename::*;
+
+// Expands an enum into an attribute and back.
+// An enum of unit variants only is a `S` of the variant name,
+// and others are a `M` with the variant name in the tag and the data in the content, e.g.
+// `{ "type": "Moved", "content": { "x": 1, "y": 2 } }` for `Shape::Moved { x: 1, y: 2 }`.
+pub fn expand_enum_attribute(
+    enum_name: &proc_macro2::Ident,
+    data: &syn::DataEnum,
+    attrs: &[syn::Attribute],
+    rename_all_type: crate::rename::RenameAllType,
+) -> proc_macro2::TokenStream {
+    let variant_names: Vec<String> = data
+        .variants
+        .iter()
+        .map(|v| {
+            let renamed = crate::finder::find_rename_value(&v.attrs);
+            create_renamed_variant(v.ident.to_string(), renamed, rename_all_type)
+        })
+        .collect();
+
+    let unknown_variant = quote! {
+        variant => Err(::raiden::ConversionError::UnknownVariant(stringify!(#enum_name).to_owned(), variant.to_owned()))
+    };
+
+    if data
+        .variants
+        .iter()
+        .all(|v| matches!(v.fields, syn::Fields::Unit))
+    {
+        let idents: Vec<&syn::Ident> = data.variants.iter().map(|v| &v.ident).collect();
+        return quote! {
+            impl ::raiden::IntoAttribute for #enum_name {
+                fn into_attr(self) -> ::raiden::AttributeValue {
+                    let name = match self {
+                        #(#enum_name::#idents => #variant_names,)*
+                    };
+                    ::raiden::AttributeValue {
+                        s: Some(name.to_owned()),
+                        ..::raiden::AttributeValue::default()
+                    }
+                }
+            }
+
+            impl ::raiden::FromAttribute for #enum_name {
+                fn from_attr(value: Option<::raiden::AttributeValue>) -> Result<Self, ::raiden::ConversionError> {
+                    let name = value.and_then(|value| value.s).ok_or(::raiden::ConversionError::ValueIsNone)?;
+                    match name.as_str() {
+                        #(#variant_names => Ok(#enum_name::#idents),)*
+                        #unknown_variant,
+                    }
+                }
+            }
+        };
+    }
+
+    let tag = crate::finder::find_tag_name(attrs).unwrap_or_else(|| "type".to_owned());
+    let content = crate::finder::find_content_name(attrs).unwrap_or_else(|| "content".to_owned());
+
+    let into_arms = data
+        .variants
+        .iter()
+        .zip(variant_names.iter())
+        .map(|(v, name)| {
+            let ident = &v.ident;
+            let (pattern, value) = match &v.fields {
+                syn::Fields::Unit => (quote! { #enum_name::#ident }, quote! { None }),
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (
+                    quote! { #enum_name::#ident(v0) },
+                    quote! { Some(::raiden::IntoAttribute::into_attr(v0)) },
+                ),
+                syn::Fields::Unnamed(fields) => {
+                    let vars: Vec<proc_macro2::Ident> = (0..fields.unnamed.len())
+                        .map(|i| format_ident!("v{}", i))
+                        .collect();
+                    (
+                        quote! { #enum_name::#ident(#(#vars),*) },
+                        quote! {
+                            Some(::raiden::AttributeValue {
+                                l: Some(vec![#(::raiden::IntoAttribute::into_attr(#vars)),*]),
+                                ..::raiden::AttributeValue::default()
+                            })
+                        },
+                    )
+                }
+                syn::Fields::Named(fields) => {
+                    let vars: Vec<&syn::Ident> = fields
+                        .named
+                        .iter()
+                        .map(|f| f.ident.as_ref().unwrap())
+                        .collect();
+                    let keys = fields.named.iter().map(|f| {
+                        let renamed = crate::finder::find_rename_value(&f.attrs);
+                        create_renamed(
+                            f.ident.as_ref().unwrap().to_string(),
+                            renamed,
+                            RenameAllType::None,
+                        )
+                    });
+                    (
+                        quote! { #enum_name::#ident { #(#vars),* } },
+                        quote! {{
+                            let mut m: ::raiden::AttributeValues = std::collections::HashMap::new();
+                            #(
+                                let value = ::raiden::IntoAttribute::into_attr(#vars);
+                                if !::raiden::is_attr_value_empty(&value) {
+                                    m.insert(#keys.to_owned(), value);
+                                }
+                            )*
+                            Some(::raiden::AttributeValue {
+                                m: Some(m),
+                                ..::raiden::AttributeValue::default()
+                            })
+                        }},
+                    )
+                }
+            };
+            quote! {
+                #pattern => (#name, #value)
+            }
+        });
+
+    let from_arms = data.variants.iter().zip(variant_names.iter()).map(|(v, name)| {
+        let ident = &v.ident;
+        let value = match &v.fields {
+            syn::Fields::Unit => quote! { #enum_name::#ident },
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                #enum_name::#ident(::raiden::FromAttribute::from_attr(content)?)
+            },
+            syn::Fields::Unnamed(fields) => {
+                let values = fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    let index = i.to_string();
+                    quote! {
+                        ::raiden::FromAttribute::from_attr(list.next())
+                            .map_err(|e| ::raiden::ConversionError::Field(#index.to_owned(), Box::new(e)))?
+                    }
+                });
+                quote! {{
+                    let mut list = content.and_then(|content| content.l).ok_or_else(|| ::raiden::ConversionError::Field(#content.to_owned(), Box::new(::raiden::ConversionError::ValueIsNone)))?.into_iter();
+                    #enum_name::#ident(#(#values),*)
+                }}
+            }
+            syn::Fields::Named(fields) => {
+                let values = fields.named.iter().map(|f| {
+                    let field_ident = f.ident.as_ref().unwrap();
+                    let renamed = crate::finder::find_rename_value(&f.attrs);
+                    let key = create_renamed(field_ident.to_string(), renamed, RenameAllType::None);
+                    quote! {
+                        #field_ident: ::raiden::FromAttribute::from_attr(m.get(#key).cloned())
+                            .map_err(|e| ::raiden::ConversionError::Field(#key.to_owned(), Box::new(e)))?
+                    }
+                });
+                quote! {{
+                    let m = content.and_then(|content| content.m).ok_or_else(|| ::raiden::ConversionError::Field(#content.to_owned(), Box::new(::raiden::ConversionError::ValueIsNone)))?;
+                    #enum_name::#ident { #(#values),* }
+                }}
+            }
+        };
+        quote! {
+            #name => Ok(#value)
+        }
+    });
+
+    quote! {
+        impl ::raiden::IntoAttribute for #enum_name {
+            fn into_attr(self) -> ::raiden::AttributeValue {
+                let (name, content): (&str, Option<::raiden::AttributeValue>) = match self {
+                    #(#into_arms,)*
+                };
+                let mut m: ::raiden::AttributeValues = std::collections::HashMap::new();
+                m.insert(#tag.to_owned(), ::raiden::IntoAttribute::into_attr(name.to_owned()));
+                if let Some(content) = content {
+                    if !::raiden::is_attr_value_empty(&content) {
+                        m.insert(#content.to_owned(), content);
+                    }
+                }
+                ::raiden::AttributeValue {
+                    m: Some(m),
+                    ..::raiden::AttributeValue::default()
+                }
+            }
+        }
+
+        impl ::raiden::FromAttribute for #enum_name {
+            fn from_attr(value: Option<::raiden::AttributeValue>) -> Result<Self, ::raiden::ConversionError> {
+                let mut item = value.and_then(|value| value.m).ok_or(::raiden::ConversionError::ValueIsNone)?;
+                let name = item
+                    .remove(#tag)
+                    .and_then(|name| name.s)
+                    .ok_or_else(|| ::raiden::ConversionError::Field(#tag.to_owned(), Box::new(::raiden::ConversionError::ValueIsNone)))?;
+                let content = item.remove(#content);
+                match name.as_str() {
+                    #(#from_arms,)*
+                    #unknown_variant,
+                }
+            }
+        }
+    }
+}
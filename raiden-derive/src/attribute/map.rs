@@ -0,0 +1,63 @@
+use quote::*;
+
+use crate::rename::*;
+
+// Expands a named struct into a `M` attribute and back, so that it can be a field of a `Raiden` struct.
+pub fn expand_map_attribute(
+    struct_name: &proc_macro2::Ident,
+    fields: &syn::FieldsNamed,
+    rename_all_type: crate::rename::RenameAllType,
+) -> proc_macro2::TokenStream {
+    let insertion = fields.named.iter().map(|f| {
+        let ident = &f.ident.clone().unwrap();
+        let renamed = crate::finder::find_rename_value(&f.attrs);
+        let attr_key = create_renamed(ident.to_string(), renamed, rename_all_type);
+        let into_attr = crate::attribute::expand_into_attr(f, quote! { self.#ident });
+        quote! {
+            let value = #into_attr;
+            if !::raiden::is_attr_value_empty(&value) {
+                m.insert(#attr_key.to_string(), value);
+            }
+        }
+    });
+
+    let from_item =
+        crate::ops::expand_attr_to_item(&format_ident!("item"), fields, rename_all_type);
+
+    quote! {
+        impl ::raiden::IntoAttribute for #struct_name {
+            fn into_attr(self) -> ::raiden::AttributeValue {
+                let mut m: ::raiden::AttributeValues = std::collections::HashMap::new();
+                #(#insertion)*
+                ::raiden::AttributeValue {
+                    m: Some(m),
+                    ..::raiden::AttributeValue::default()
+                }
+            }
+        }
+
+        impl ::raiden::FromItem for #struct_name {
+            fn from_item(item: &::raiden::AttributeValues) -> Result<Self, ::raiden::RaidenError> {
+                Ok(#struct_name {
+                    #(#from_item)*
+                })
+            }
+        }
+
+        impl ::raiden::FromAttribute for #struct_name {
+            fn from_attr(value: Option<::raiden::AttributeValue>) -> Result<Self, ::raiden::ConversionError> {
+                let item = value.and_then(|value| value.m).ok_or(::raiden::ConversionError::ValueIsNone)?;
+                ::raiden::FromItem::from_item(&item).map_err(|e| match e {
+                    ::raiden::RaidenError::AttributeConvertError { attr_name, source } => {
+                        ::raiden::ConversionError::Field(attr_name, Box::new(source))
+                    }
+                    ::raiden::RaidenError::AttributeValueNotFoundError { attr_name } => {
+                        ::raiden::ConversionError::Field(attr_name, Box::new(::raiden::ConversionError::ValueIsNone))
+                    }
+                    // `from_item` of the struct fails only with the errors above.
+                    e => ::raiden::ConversionError::Field(e.to_string(), Box::new(::raiden::ConversionError::ValueIsNone)),
+                })
+            }
+        }
+    }
+}
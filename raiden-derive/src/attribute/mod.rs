@@ -1,3 +1,9 @@
+pub mod enums;
+pub mod map;
 pub mod names;
+pub mod time_format;
 
+pub(crate) use enums::*;
+pub(crate) use map::*;
 pub(crate) use names::*;
+pub(crate) use time_format::*;
@@ -3,10 +3,25 @@ use quote::*;
 use crate::rename::*;
 use convert_case::{Case, Casing};
 
-// TODO: Add map and list accessor
-//       e.g. MyMap.nestedField.deeplyNestedField
-//       Should we annotate map or list accessor with following derive?
-//       #[raiden(expression_name = "MyMap.nestedField.deeplyNestedField")]
+// Returns the attr enum variant of the field and the name of the accessors generated for it,
+// e.g. `UserAttrNames::Age` and `age` for `User::age()`.
+pub(crate) fn expand_field_accessor(
+    f: &syn::Field,
+    rename_all_type: crate::rename::RenameAllType,
+) -> (proc_macro2::Ident, proc_macro2::Ident) {
+    let ident = &f.ident.clone().unwrap();
+    let renamed = crate::finder::find_rename_value(&f.attrs);
+    let basename = create_renamed(ident.to_string(), renamed, rename_all_type);
+    let func_name = basename.to_case(Case::Snake);
+    let func_name = if crate::helpers::is_reserved(&func_name) {
+        format_ident!("r#{}", func_name)
+    } else {
+        format_ident!("{}", func_name)
+    };
+    let name = format_ident!("{}", basename.to_case(Case::Pascal));
+    (name, func_name)
+}
+
 pub fn expand_attr_names(
     attr_enum_name: &proc_macro2::Ident,
     fields: &syn::FieldsNamed,
@@ -41,17 +56,7 @@ pub fn expand_attr_names(
     });
 
     let getters = fields.named.iter().map(|f| {
-        let ident = &f.ident.clone().unwrap();
-        let renamed = crate::finder::find_rename_value(&f.attrs);
-        let basename = create_renamed(ident.to_string(), renamed, rename_all_type);
-        let func_name = basename.to_case(Case::Snake);
-        let func_name = if crate::helpers::is_reserved(&func_name) {
-            format_ident!("r#{}", func_name)
-        } else {
-            format_ident!("{}", func_name)
-        };
-        let name = basename.to_case(Case::Pascal);
-        let name = format_ident!("{}", name);
+        let (name, func_name) = expand_field_accessor(f, rename_all_type);
         quote! {
             pub fn #func_name() -> #attr_enum_name {
                 #attr_enum_name::#name
@@ -77,6 +82,18 @@ pub fn expand_attr_names(
             }
         }
 
+        // map and list accessor
+        // e.g. `UserAttrNames::Address.field("city")` is rendered as `#address.#city`.
+        impl #attr_enum_name {
+            pub fn field(self, name: impl Into<String>) -> ::raiden::AttrPath<Self> {
+                ::raiden::AttrPath::new(self).field(name)
+            }
+
+            pub fn index(self, index: usize) -> ::raiden::AttrPath<Self> {
+                ::raiden::AttrPath::new(self).index(index)
+            }
+        }
+
         // attr name getter
         impl #struct_name {
             #(
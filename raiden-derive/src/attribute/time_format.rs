@@ -0,0 +1,66 @@
+use quote::*;
+
+// A field with `#[raiden(time_format = "epoch_seconds")]` is converted through `::raiden::EpochSeconds<T>`,
+// and one without it or with `"iso8601"` is converted as is.
+fn parse_time_format(f: &syn::Field) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let format = match crate::finder::find_time_format(&f.attrs) {
+        Some(format) => format,
+        None => return Ok(None),
+    };
+    match format.as_str() {
+        "iso8601" => Ok(None),
+        "epoch_seconds" => Ok(Some(quote! { ::raiden::EpochSeconds })),
+        "epoch_millis" => Ok(Some(quote! { ::raiden::EpochMillis })),
+        format => Err(syn::Error::new_spanned(
+            f,
+            format!("{} is not supported time format", format),
+        )),
+    }
+}
+
+// Returns the error of the first field with an unsupported time format.
+pub(crate) fn check_time_formats<'a>(
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+) -> syn::Result<()> {
+    for f in fields {
+        parse_time_format(f)?;
+    }
+    Ok(())
+}
+
+// Unsupported formats are reported by `check_time_formats` before expanding.
+fn time_format_wrapper(f: &syn::Field) -> Option<proc_macro2::TokenStream> {
+    parse_time_format(f).unwrap_or(None)
+}
+
+// The type of the attribute value of the field, used for keys and typed builders.
+pub(crate) fn attr_type(f: &syn::Field) -> syn::Type {
+    let ty = &f.ty;
+    match time_format_wrapper(f) {
+        Some(wrapper) => syn::parse_quote! { #wrapper<#ty> },
+        None => ty.clone(),
+    }
+}
+
+pub(crate) fn expand_into_attr(
+    f: &syn::Field,
+    value: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match time_format_wrapper(f) {
+        Some(wrapper) => quote! { ::raiden::IntoAttribute::into_attr(#wrapper(#value)) },
+        None => quote! { ::raiden::IntoAttribute::into_attr(#value) },
+    }
+}
+
+pub(crate) fn expand_from_attr(
+    f: &syn::Field,
+    value: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ty = &f.ty;
+    match time_format_wrapper(f) {
+        Some(wrapper) => {
+            quote! { <#wrapper<#ty> as ::raiden::FromAttribute>::from_attr(#value).map(|v| v.0) }
+        }
+        None => quote! { ::raiden::FromAttribute::from_attr(#value) },
+    }
+}
@@ -3,17 +3,76 @@ use quote::*;
 pub fn expand_condition_builder(
     attr_enum_name: &proc_macro2::Ident,
     struct_name: &proc_macro2::Ident,
-    _fields: &syn::FieldsNamed,
+    fields: &syn::FieldsNamed,
+    rename_all_type: crate::rename::RenameAllType,
 ) -> proc_macro2::TokenStream {
     let condition_name = format_ident!("{}Condition", struct_name);
+    let condition_fields_name = format_ident!("{}ConditionFields", struct_name);
     let condition_token_name = format_ident!("{}ConditionToken", struct_name);
     let wait_attr_op_name = format_ident!("{}LeftAttrAndWaitOp", struct_name);
 
+    let binary_ops = ["eq", "ne", "lt", "le", "gt", "ge"].iter().map(|op| {
+        let attr_fn = format_ident!("{}_attr", op);
+        let value_fn = format_ident!("{}_value", op);
+        let variant = format_ident!("{}{}", op[..1].to_uppercase(), &op[1..]);
+        quote! {
+            pub fn #attr_fn(self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
+                let attr = ::raiden::AttrOrPlaceholder::Attr(attr.into_attr_path().into_document_path());
+                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::#variant(self.attr_or_placeholder, attr));
+                ConditionFilledOrWaitConjunction {
+                    not: self.not,
+                    cond,
+                    _token: std::marker::PhantomData,
+                }
+            }
+
+            pub fn #value_fn(self, value: impl ::raiden::IntoAttribute) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
+                let placeholder = ::raiden::AttrOrPlaceholder::Placeholder(value.into_attr());
+                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::#variant(self.attr_or_placeholder, placeholder));
+                ConditionFilledOrWaitConjunction {
+                    not: self.not,
+                    cond,
+                    _token: std::marker::PhantomData,
+                }
+            }
+        }
+    });
+
+    let field_conditions = fields.named.iter().map(|f| {
+        let (name, func_name) = crate::attribute::expand_field_accessor(f, rename_all_type);
+        let ty = crate::attribute::attr_type(f);
+        quote! {
+            pub fn #func_name(&self) -> ::raiden::FieldCondition<#condition_token_name, #ty> {
+                ::raiden::FieldCondition::new(#attr_enum_name::#name)
+            }
+        }
+    });
+
     quote! {
 
         #[derive(Debug, Clone)]
         pub struct #condition_token_name;
 
+        impl #condition_token_name {
+            // Uses a condition parsed from a string, e.g. by `raiden::expression::parse_condition`, for this struct.
+            pub fn from_parsed(cond: ::raiden::condition::Cond) -> ::raiden::condition::ConditionFilled<#condition_token_name> {
+                ::raiden::condition::ConditionFilled::from_parsed(cond)
+            }
+        }
+
+        // Typed conditions per field, e.g. `User::cond().age().gt(30)`.
+        pub struct #condition_fields_name;
+
+        impl #struct_name {
+            pub fn cond() -> #condition_fields_name {
+                #condition_fields_name
+            }
+        }
+
+        impl #condition_fields_name {
+            #(#field_conditions)*
+        }
+
 
         #[derive(Debug, Clone)]
         pub struct #condition_name {
@@ -33,32 +92,32 @@ pub fn expand_condition_builder(
                 self.not = true;
                 self
             }
-            pub fn attr_exists(self, field: #attr_enum_name) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
-                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::AttributeExists(field.into_attr_name()));
+            pub fn attr_exists(self, field: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
+                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::AttributeExists(field.into_attr_path().into_document_path()));
                 ConditionFilledOrWaitConjunction {
                     not: self.not,
                     cond,
                     _token: std::marker::PhantomData,
                 }
             }
-            pub fn attr_not_exists(self, field: #attr_enum_name) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
-                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::AttributeNotExists(field.into_attr_name()));
+            pub fn attr_not_exists(self, field: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
+                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::AttributeNotExists(field.into_attr_path().into_document_path()));
                 ConditionFilledOrWaitConjunction {
                     not: self.not,
                     cond,
                     _token: std::marker::PhantomData,
                 }
             }
-            pub fn attr_type(self, field: #attr_enum_name, t: ::raiden::AttributeType) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
-                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::AttributeType(field.into_attr_name(), t));
+            pub fn attr_type(self, field: impl ::raiden::IntoAttrPath<#attr_enum_name>, t: ::raiden::AttributeType) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
+                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::AttributeType(field.into_attr_path().into_document_path(), t));
                 ConditionFilledOrWaitConjunction {
                     not: self.not,
                     cond,
                     _token: std::marker::PhantomData,
                 }
             }
-            pub fn begins_with(self, field: #attr_enum_name, s: impl Into<String>) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
-                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::BeginsWith(field.into_attr_name(), s.into()));
+            pub fn begins_with(self, field: impl ::raiden::IntoAttrPath<#attr_enum_name>, s: impl ::raiden::IntoAttribute) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
+                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::BeginsWith(field.into_attr_path().into_document_path(), s.into_attr()));
                 ConditionFilledOrWaitConjunction {
                     not: self.not,
                     cond,
@@ -66,8 +125,9 @@ pub fn expand_condition_builder(
                 }
             }
 
-            pub fn contains(self, field: #attr_enum_name, s: impl Into<String>) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
-                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::Contains(field.into_attr_name(), s.into()));
+            // `value` is a substring of a string, or an element of a set or a list.
+            pub fn contains(self, field: impl ::raiden::IntoAttrPath<#attr_enum_name>, value: impl ::raiden::IntoAttribute) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
+                let cond = ::raiden::condition::Cond::Func(::raiden::condition::ConditionFunctionExpression::Contains(field.into_attr_path().into_document_path(), value.into_attr()));
                 ConditionFilledOrWaitConjunction {
                     not: self.not,
                     cond,
@@ -75,20 +135,32 @@ pub fn expand_condition_builder(
                 }
             }
 
-            pub fn attr(self, field: #attr_enum_name) -> #wait_attr_op_name {
+            pub fn group(self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> ConditionFilledOrWaitConjunction<#condition_token_name> {
+                ConditionFilledOrWaitConjunction {
+                    not: self.not,
+                    cond: cond.into_cond(),
+                    _token: std::marker::PhantomData,
+                }
+            }
+
+            pub fn attr(self, field: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> #wait_attr_op_name {
                 #wait_attr_op_name {
                     not: self.not,
-                    attr_or_placeholder: ::raiden::AttrOrPlaceholder::Attr(field.into_attr_name()),
-                    attr_value: None,
+                    attr_or_placeholder: ::raiden::AttrOrPlaceholder::Attr(field.into_attr_path().into_document_path()),
+                }
+            }
+
+            pub fn size(self, field: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> #wait_attr_op_name {
+                #wait_attr_op_name {
+                    not: self.not,
+                    attr_or_placeholder: ::raiden::AttrOrPlaceholder::Size(field.into_attr_path().into_document_path()),
                 }
             }
 
             pub fn value(self, value: impl ::raiden::IntoAttribute) -> #wait_attr_op_name {
-                let placeholder = format!("value{}", ::raiden::generate_value_id());
                 #wait_attr_op_name {
                     not: self.not,
-                    attr_or_placeholder: ::raiden::AttrOrPlaceholder::Placeholder(placeholder),
-                    attr_value: Some(value.into_attr()),
+                    attr_or_placeholder: ::raiden::AttrOrPlaceholder::Placeholder(value.into_attr()),
                 }
             }
         }
@@ -96,24 +168,46 @@ pub fn expand_condition_builder(
         pub struct #wait_attr_op_name {
             not: bool,
             attr_or_placeholder: ::raiden::AttrOrPlaceholder,
-            attr_value: Option<::raiden::AttributeValue>
         }
 
         impl #wait_attr_op_name {
-            fn eq_attr(self, attr: #attr_enum_name) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
-                let attr = ::raiden::AttrOrPlaceholder::Attr(attr.into_attr_name());
-                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::Eq(self.attr_or_placeholder, self.attr_value, attr, None));
+            #(#binary_ops)*
+
+            pub fn between_attr(self, lower: impl ::raiden::IntoAttrPath<#attr_enum_name>, upper: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
+                let lower = ::raiden::AttrOrPlaceholder::Attr(lower.into_attr_path().into_document_path());
+                let upper = ::raiden::AttrOrPlaceholder::Attr(upper.into_attr_path().into_document_path());
+                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::Between(self.attr_or_placeholder, lower, upper));
                 ConditionFilledOrWaitConjunction {
                     not: self.not,
                     cond,
                     _token: std::marker::PhantomData,
                 }
+            }
 
+            pub fn between_value(self, lower: impl ::raiden::IntoAttribute, upper: impl ::raiden::IntoAttribute) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
+                let lower = ::raiden::AttrOrPlaceholder::Placeholder(lower.into_attr());
+                let upper = ::raiden::AttrOrPlaceholder::Placeholder(upper.into_attr());
+                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::Between(self.attr_or_placeholder, lower, upper));
+                ConditionFilledOrWaitConjunction {
+                    not: self.not,
+                    cond,
+                    _token: std::marker::PhantomData,
+                }
+            }
+
+            pub fn in_attr<A: ::raiden::IntoAttrPath<#attr_enum_name>>(self, attrs: Vec<A>) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
+                let list = attrs.into_iter().map(|attr| ::raiden::AttrOrPlaceholder::Attr(attr.into_attr_path().into_document_path())).collect();
+                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::In(self.attr_or_placeholder, list));
+                ConditionFilledOrWaitConjunction {
+                    not: self.not,
+                    cond,
+                    _token: std::marker::PhantomData,
+                }
             }
 
-            fn eq_value(self, value: impl ::raiden::IntoAttribute) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
-                let placeholder = ::raiden::AttrOrPlaceholder::Placeholder(format!("value{}", ::raiden::generate_value_id()));
-                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::Eq(self.attr_or_placeholder, self.attr_value, placeholder, Some(value.into_attr())));
+            pub fn in_value<T: ::raiden::IntoAttribute>(self, values: Vec<T>) -> ConditionFilledOrWaitConjunction<#condition_token_name>  {
+                let list = values.into_iter().map(|value| ::raiden::AttrOrPlaceholder::Placeholder(value.into_attr())).collect();
+                let cond = ::raiden::condition::Cond::Cmp(::raiden::condition::ConditionComparisonExpression::In(self.attr_or_placeholder, list));
                 ConditionFilledOrWaitConjunction {
                     not: self.not,
                     cond,
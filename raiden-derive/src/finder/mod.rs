@@ -66,6 +66,33 @@ pub(crate) fn find_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
     None
 }
 
+pub(crate) fn find_tag_name(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        if let Some(lit) = find_eq_string_from(attr, "tag") {
+            return Some(lit);
+        }
+    }
+    None
+}
+
+pub(crate) fn find_content_name(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        if let Some(lit) = find_eq_string_from(attr, "content") {
+            return Some(lit);
+        }
+    }
+    None
+}
+
+pub(crate) fn find_time_format(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        if let Some(lit) = find_eq_string_from(attr, "time_format") {
+            return Some(lit);
+        }
+    }
+    None
+}
+
 pub(crate) fn find_rename_value(attrs: &[syn::Attribute]) -> Option<String> {
     for attr in attrs {
         if let Some(lit) = find_eq_string_from(&attr, "rename") {
@@ -11,18 +11,19 @@ pub fn fetch_partition_key(
 ) -> (Ident, Type) {
     match find_partition_key_field(&fields) {
         Some(key) => {
+            let ty = crate::attribute::attr_type(&key);
             // Rename partition key if renamed.
             let renamed = find_rename_value(&key.attrs);
             if renamed.is_some() {
-                (format_ident!("{}", renamed.unwrap()), key.ty)
+                (format_ident!("{}", renamed.unwrap()), ty)
             } else if rename_all_type != RenameAllType::None {
                 let ident = format_ident!(
                     "{}",
                     rename(rename_all_type, key.ident.unwrap().to_string())
                 );
-                (ident, key.ty)
+                (ident, ty)
             } else {
-                (key.ident.unwrap(), key.ty)
+                (key.ident.unwrap(), ty)
             }
         }
         None => panic!("Please specify partition key"),
@@ -35,18 +36,19 @@ pub fn fetch_sort_key(
 ) -> Option<(Ident, Type)> {
     match find_sort_key_field(&fields) {
         Some(key) => {
+            let ty = crate::attribute::attr_type(&key);
             // Rename partition key if renamed.
             let renamed = find_rename_value(&key.attrs);
             if renamed.is_some() {
-                Some((format_ident!("{}", renamed.unwrap()), key.ty))
+                Some((format_ident!("{}", renamed.unwrap()), ty))
             } else if rename_all_type != RenameAllType::None {
                 let ident = format_ident!(
                     "{}",
                     rename(rename_all_type, key.ident.unwrap().to_string())
                 );
-                Some((ident, key.ty))
+                Some((ident, ty))
             } else {
-                Some((key.ident.unwrap(), key.ty))
+                Some((key.ident.unwrap(), ty))
             }
         }
         None => None,
@@ -3,15 +3,49 @@ use quote::*;
 pub fn expand_key_condition_builder(
     attr_enum_name: &proc_macro2::Ident,
     struct_name: &proc_macro2::Ident,
+    fields: &syn::FieldsNamed,
+    rename_all_type: crate::rename::RenameAllType,
 ) -> proc_macro2::TokenStream {
     let key_condition_token_name = format_ident!("{}KeyConditionToken", struct_name);
+    let key_condition_fields_name = format_ident!("{}KeyConditionFields", struct_name);
+
+    let field_key_conditions = fields.named.iter().map(|f| {
+        let (name, func_name) = crate::attribute::expand_field_accessor(f, rename_all_type);
+        let ty = crate::attribute::attr_type(f);
+        quote! {
+            pub fn #func_name(&self) -> ::raiden::FieldKeyCondition<#key_condition_token_name, #ty> {
+                ::raiden::FieldKeyCondition::new(#attr_enum_name::#name)
+            }
+        }
+    });
+
     quote! {
 
         pub struct #key_condition_token_name;
 
+        impl #key_condition_token_name {
+            // Uses a key condition parsed from a string, e.g. by `raiden::expression::parse_key_condition`, for this struct.
+            pub fn from_parsed(cond: ::raiden::KeyCond) -> ::raiden::KeyConditionFilled<#key_condition_token_name> {
+                ::raiden::KeyConditionFilled::from_parsed(cond)
+            }
+        }
+
+        // Typed key conditions per field, e.g. `User::key_cond().id().eq("id0")`.
+        pub struct #key_condition_fields_name;
+
+        impl #struct_name {
+            pub fn key_cond() -> #key_condition_fields_name {
+                #key_condition_fields_name
+            }
+        }
+
+        impl #key_condition_fields_name {
+            #(#field_key_conditions)*
+        }
+
         impl #struct_name {
-            pub fn key_condition(attr: #attr_enum_name) -> ::raiden::KeyCondition<#key_condition_token_name> {
-                let attr = attr.into_attr_name();
+            pub fn key_condition(attr: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ::raiden::KeyCondition<#key_condition_token_name> {
+                let attr = attr.into_attr_path().into_document_path();
                 ::raiden::KeyCondition {
                     attr,
                     _token: std::marker::PhantomData,
@@ -45,9 +45,20 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
             fields: Fields::Named(n),
             ..
         }) => n,
-        _ => unimplemented!(),
+        _ => {
+            return syn::Error::new_spanned(
+                &struct_name,
+                "Raiden can only be derived for structs with named fields",
+            )
+            .to_compile_error()
+            .into()
+        }
     };
 
+    if let Err(e) = attribute::check_time_formats(&fields.named) {
+        return e.to_compile_error().into();
+    }
+
     let partition_key = key::fetch_partition_key(&fields, rename_all_type);
     let sort_key = key::fetch_sort_key(&fields, rename_all_type);
 
@@ -75,7 +86,13 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
         rename_all_type,
     );
 
-    let query = ops::expand_query(&struct_name, &fields, rename_all_type);
+    let query = ops::expand_query(
+        &partition_key,
+        &sort_key,
+        &struct_name,
+        &fields,
+        rename_all_type,
+    );
 
     let scan = ops::expand_scan(&struct_name, &fields, rename_all_type);
 
@@ -87,6 +104,14 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
         rename_all_type,
     );
 
+    let batch_write = ops::expand_batch_write(
+        &partition_key,
+        &sort_key,
+        &struct_name,
+        &fields,
+        rename_all_type,
+    );
+
     let put_item = ops::expand_put_item(&struct_name, &fields, rename_all_type);
 
     let update_item = ops::expand_update_item(
@@ -103,11 +128,19 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
     let attr_names =
         attribute::expand_attr_names(&attr_enum_name, &fields, rename_all_type, &struct_name);
 
-    let condition_builder =
-        condition::expand_condition_builder(&attr_enum_name, &struct_name, &fields);
+    let condition_builder = condition::expand_condition_builder(
+        &attr_enum_name,
+        &struct_name,
+        &fields,
+        rename_all_type,
+    );
 
-    let key_condition_builder =
-        key_condition::expand_key_condition_builder(&attr_enum_name, &struct_name);
+    let key_condition_builder = key_condition::expand_key_condition_builder(
+        &attr_enum_name,
+        &struct_name,
+        &fields,
+        rename_all_type,
+    );
 
     let transact_write = ops::expand_transact_write(
         &struct_name,
@@ -119,6 +152,17 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
         &table_name,
     );
 
+    let transact_get = ops::expand_transact_get(
+        &struct_name,
+        &partition_key,
+        &sort_key,
+        &fields,
+        rename_all_type,
+        &table_name,
+    );
+
+    let from_item = ops::expand_attr_to_item(&format_ident!("item"), &fields, rename_all_type);
+
     let insertion_attribute_name = fields.named.iter().map(|f| {
         let ident = &f.ident.clone().unwrap();
         let renamed = crate::finder::find_rename_value(&f.attrs);
@@ -154,6 +198,8 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
 
         #batch_get
 
+        #batch_write
+
         #query
 
         #scan
@@ -166,6 +212,8 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
 
         #transact_write
 
+        #transact_get
+
         impl #client_name {
             pub fn new(region: ::raiden::Region) -> Self {
                 let client = DynamoDbClient::new(region);
@@ -214,11 +262,53 @@ pub fn derive_raiden(input: TokenStream) -> TokenStream {
         }
 
         impl ::raiden::IdGenerator for #struct_name {}
+
+        impl ::raiden::FromItem for #struct_name {
+            fn from_item(item: &::raiden::AttributeValues) -> Result<Self, ::raiden::RaidenError> {
+                Ok(#struct_name {
+                    #(#from_item)*
+                })
+            }
+        }
     };
     // Hand the output tokens back to the compiler.
     proc_macro::TokenStream::from(expanded)
 }
 
+#[proc_macro_derive(RaidenAttribute, attributes(raiden))]
+pub fn derive_raiden_attribute(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    let struct_name = input.ident;
+
+    let rename_all = finder::find_rename_all(&input.attrs);
+    let rename_all_type = if let Some(rename_all) = rename_all {
+        rename::RenameAllType::from_str(&rename_all).unwrap()
+    } else {
+        rename::RenameAllType::None
+    };
+
+    let expanded = match input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(n),
+            ..
+        }) => match attribute::check_time_formats(&n.named) {
+            Ok(()) => attribute::expand_map_attribute(&struct_name, &n, rename_all_type),
+            Err(e) => e.to_compile_error(),
+        },
+        Data::Enum(e) => {
+            attribute::expand_enum_attribute(&struct_name, &e, &input.attrs, rename_all_type)
+        }
+        _ => syn::Error::new_spanned(
+            &struct_name,
+            "RaidenAttribute can only be derived for structs with named fields or enums",
+        )
+        .to_compile_error(),
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
 // fn fetch_raiden_field(fields: &syn::FieldsNamed) -> Vec<syn::Field> {
 //     let fields: Vec<syn::Field> = fields
 //         .named
@@ -48,7 +48,9 @@ pub(crate) fn expand_batch_get(
             table_name: self.table_name(),
             keys: key_attrs,
             attribute_names: Some(names),
-            projection_expression
+            projection_expression,
+            policy: self.retry_condition.strategy.policy(),
+            condition: &self.retry_condition,
         }
     };
 
@@ -117,13 +119,15 @@ pub(crate) fn expand_batch_get(
             pub table_name: String,
             pub keys: #builder_keys_type,
             pub attribute_names: Option<::raiden::AttributeNames>,
-            pub projection_expression: Option<String>
+            pub projection_expression: Option<String>,
+            pub policy: ::raiden::Policy,
+            pub condition: &'a ::raiden::retry::RetryCondition,
         }
 
         impl<'a> #builder_name<'a> {
 
             #![allow(clippy::field_reassign_with_default)]
-            async fn run(mut self) -> Result<::raiden::batch_get::BatchGetOutput<#struct_name>, ::raiden::RaidenError> {
+            pub async fn run(mut self) -> Result<::raiden::batch_get::BatchGetOutput<#struct_name>, ::raiden::RaidenError> {
                 let mut items: std::vec::Vec<#struct_name> = vec![];
                 let mut unprocessed_keys = ::raiden::KeysAndAttributes::default();
 
@@ -150,7 +154,15 @@ pub(crate) fn expand_batch_get(
                         .request_items
                         .insert(self.table_name.to_string(), item);
 
-                    let res = self.client.batch_get_item(input).await?;
+                    let policy: ::raiden::RetryPolicy = self.policy.into();
+                    let client = self.client;
+                    let res = policy.retry_if(|| {
+                        let client = client.clone();
+                        let input = input.clone();
+                        async {
+                            #builder_name::inner_run(client, input).await
+                        }
+                    }, self.condition).await?;
 
                     if self.keys.is_empty() {
                         unprocessed_retry -= 1;
@@ -188,6 +200,10 @@ pub(crate) fn expand_batch_get(
                     }
                 }
             }
+
+            async fn inner_run(client: ::raiden::DynamoDbClient, input: ::raiden::BatchGetItemInput) -> Result<::raiden::BatchGetItemOutput, ::raiden::RaidenError> {
+                Ok(client.batch_get_item(input).await?)
+            }
         }
     }
 }
@@ -0,0 +1,212 @@
+use proc_macro2::*;
+use quote::*;
+use syn::*;
+
+use crate::rename::*;
+
+pub(crate) fn expand_batch_write(
+    partition_key: &(Ident, Type),
+    sort_key: &Option<(Ident, Type)>,
+    struct_name: &Ident,
+    fields: &FieldsNamed,
+    rename_all_type: RenameAllType,
+) -> proc_macro2::TokenStream {
+    let trait_name = format_ident!("{}BatchWriteItem", struct_name);
+    let client_name = format_ident!("{}Client", struct_name);
+    let builder_name = format_ident!("{}BatchWriteItemBuilder", struct_name);
+    let item_input_name = format_ident!("{}PutItemInput", struct_name);
+    let (partition_key_ident, partition_key_type) = partition_key;
+
+    let insertion = fields.named.iter().map(|f| {
+        let ident = &f.ident.clone().unwrap();
+        let renamed = crate::finder::find_rename_value(&f.attrs);
+        let attr_key = create_renamed(ident.to_string(), renamed, rename_all_type);
+        let into_attr = crate::attribute::expand_into_attr(f, quote! { item.#ident.clone() });
+        if crate::finder::include_unary_attr(&f.attrs, "uuid") {
+            quote! {
+                input_item.insert(
+                    #attr_key.to_string(),
+                    #struct_name::gen().into_attr(),
+                );
+            }
+        } else {
+            quote! {
+                let value = #into_attr;
+                if !::raiden::is_attr_value_empty(&value) {
+                    input_item.insert(
+                        #attr_key.to_string(),
+                        value,
+                    );
+                }
+            }
+        }
+    });
+
+    let delete_fn = if let Some(sort_key) = sort_key {
+        let (sort_key_ident, sort_key_type) = sort_key;
+        quote! {
+            pub fn delete(mut self, pk: impl Into<#partition_key_type>, sk: impl Into<#sort_key_type>) -> Self {
+                let pk_attr: AttributeValue = pk.into().into_attr();
+                let sk_attr: AttributeValue = sk.into().into_attr();
+                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
+                key_set.insert(stringify!(#partition_key_ident).to_owned(), pk_attr);
+                key_set.insert(stringify!(#sort_key_ident).to_owned(), sk_attr);
+                self.write_requests.push(::raiden::WriteRequest {
+                    delete_request: Some(::raiden::DeleteRequest { key: key_set }),
+                    put_request: None,
+                });
+                self
+            }
+        }
+    } else {
+        quote! {
+            pub fn delete(mut self, key: impl Into<#partition_key_type>) -> Self {
+                let key_attr: AttributeValue = key.into().into_attr();
+                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
+                key_set.insert(stringify!(#partition_key_ident).to_owned(), key_attr);
+                self.write_requests.push(::raiden::WriteRequest {
+                    delete_request: Some(::raiden::DeleteRequest { key: key_set }),
+                    put_request: None,
+                });
+                self
+            }
+        }
+    };
+
+    quote! {
+        pub trait #trait_name {
+            fn batch_write(&self) -> #builder_name;
+        }
+
+        impl #trait_name for #client_name {
+            fn batch_write(&self) -> #builder_name {
+                #builder_name {
+                    client: &self.client,
+                    table_name: self.table_name(),
+                    write_requests: vec![],
+                    policy: self.retry_condition.strategy.policy(),
+                    condition: &self.retry_condition,
+                }
+            }
+        }
+
+        pub struct #builder_name<'a> {
+            pub client: &'a ::raiden::DynamoDbClient,
+            pub table_name: String,
+            pub write_requests: std::vec::Vec<::raiden::WriteRequest>,
+            pub policy: ::raiden::Policy,
+            pub condition: &'a ::raiden::retry::RetryCondition,
+        }
+
+        impl<'a> #builder_name<'a> {
+            pub fn put(mut self, item: #item_input_name) -> Self {
+                let mut input_item: std::collections::HashMap<String, raiden::AttributeValue> = std::collections::HashMap::new();
+                #(#insertion)*
+                self.write_requests.push(::raiden::WriteRequest {
+                    delete_request: None,
+                    put_request: Some(::raiden::PutRequest { item: input_item }),
+                });
+                self
+            }
+
+            #delete_fn
+
+            pub async fn run(mut self) -> Result<::raiden::batch_write::BatchWriteOutput, ::raiden::RaidenError> {
+                let mut consumed_capacity: Option<std::vec::Vec<::raiden::ConsumedCapacity>> = None;
+                let mut unprocessed_items: std::vec::Vec<::raiden::WriteRequest> = vec![];
+                let policy: ::raiden::RetryPolicy = self.policy.into();
+
+                // BatchWriteItem accepts up to 25 requests at once.
+                while !self.write_requests.is_empty() {
+                    let len = std::cmp::min(25, self.write_requests.len());
+                    let pending = std::sync::Arc::new(std::sync::Mutex::new(self.write_requests.drain(0..len).collect::<std::vec::Vec<_>>()));
+                    let capacity = std::sync::Arc::new(std::sync::Mutex::new(None));
+                    let client = self.client;
+                    let table_name = &self.table_name;
+
+                    // Leftover `UnprocessedItems` are reported as `ProvisionedThroughputExceeded`,
+                    // so they are resubmitted with the same backoff as a throttled request.
+                    let res = policy.retry_if(|| {
+                        let client = client.clone();
+                        let table_name = table_name.clone();
+                        let pending = pending.clone();
+                        let capacity = capacity.clone();
+                        async {
+                            #builder_name::inner_run(client, table_name, pending, capacity).await
+                        }
+                    }, self.condition).await;
+
+                    match res {
+                        Ok(()) | Err(::raiden::RaidenError::ProvisionedThroughputExceeded(_)) => {}
+                        Err(e) => return Err(e),
+                    }
+
+                    if let Some(capacity) = capacity.lock().unwrap().take() {
+                        ::raiden::batch_write::merge_consumed_capacity(&mut consumed_capacity, capacity);
+                    }
+                    unprocessed_items.append(&mut pending.lock().unwrap());
+                }
+
+                Ok(::raiden::batch_write::BatchWriteOutput {
+                    consumed_capacity,
+                    unprocessed_items,
+                })
+            }
+
+            async fn inner_run(
+                client: ::raiden::DynamoDbClient,
+                table_name: String,
+                pending: std::sync::Arc<std::sync::Mutex<std::vec::Vec<::raiden::WriteRequest>>>,
+                capacity: std::sync::Arc<std::sync::Mutex<Option<std::vec::Vec<::raiden::ConsumedCapacity>>>>,
+            ) -> Result<(), ::raiden::RaidenError> {
+                let requests = pending.lock().unwrap().clone();
+                let mut request_items: std::collections::HashMap<String, std::vec::Vec<::raiden::WriteRequest>> = std::collections::HashMap::new();
+                request_items.insert(table_name.clone(), requests);
+                let input = ::raiden::BatchWriteItemInput {
+                    request_items,
+                    return_consumed_capacity: Some("TOTAL".to_owned()),
+                    ..::raiden::BatchWriteItemInput::default()
+                };
+                let res = client.batch_write_item(input).await?;
+
+                // Resubmitted requests consume capacity too, so it is summed up over the retries.
+                if let Some(res_capacity) = res.consumed_capacity {
+                    ::raiden::batch_write::merge_consumed_capacity(&mut capacity.lock().unwrap(), res_capacity);
+                }
+
+                let unprocessed = res
+                    .unprocessed_items
+                    .and_then(|mut items| items.remove(&table_name))
+                    .unwrap_or_default();
+                let unprocessed_len = unprocessed.len();
+                *pending.lock().unwrap() = unprocessed;
+
+                if unprocessed_len > 0 {
+                    return Err(::raiden::RaidenError::ProvisionedThroughputExceeded(format!("{} items are unprocessed", unprocessed_len)));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/*
+https://github.com/rusoto/rusoto/blob/69e7c9150d98916ef8fc814f5cd17eb0e4dee3d3/rusoto/services/dynamodb/src/generated.rs#L426
+
+pub struct BatchWriteItemInput {
+    pub request_items: ::std::collections::HashMap<String, Vec<WriteRequest>>,
+    pub return_consumed_capacity: Option<String>,
+    pub return_item_collection_metrics: Option<String>,
+}
+
+pub struct BatchWriteItemOutput {
+    pub consumed_capacity: Option<Vec<ConsumedCapacity>>,
+    pub item_collection_metrics: Option<::std::collections::HashMap<String, Vec<ItemCollectionMetrics>>>,
+    pub unprocessed_items: Option<::std::collections::HashMap<String, Vec<WriteRequest>>>,
+}
+
+pub struct WriteRequest {
+    pub delete_request: Option<DeleteRequest>,
+    pub put_request: Option<PutRequest>,
+}
+*/
@@ -33,6 +33,8 @@ pub(crate) fn expand_delete_item(
                     #builder_name {
                         client: &self.client,
                         input,
+                        policy: self.retry_condition.strategy.policy(),
+                        condition: &self.retry_condition,
                     }
                 }
             }
@@ -54,6 +56,8 @@ pub(crate) fn expand_delete_item(
                     #builder_name {
                         client: &self.client,
                         input,
+                        policy: self.retry_condition.strategy.policy(),
+                        condition: &self.retry_condition,
                     }
                 }
             }
@@ -66,6 +70,8 @@ pub(crate) fn expand_delete_item(
         pub struct #builder_name<'a> {
             pub client: &'a ::raiden::DynamoDbClient,
             pub input: ::raiden::DeleteItemInput,
+            pub policy: ::raiden::Policy,
+            pub condition: &'a ::raiden::retry::RetryCondition,
         }
 
         impl<'a> #builder_name<'a> {
@@ -86,8 +92,21 @@ pub(crate) fn expand_delete_item(
                 self
             }
 
-            async fn run(self) -> Result<(), ::raiden::RaidenError> {
-                let res = self.client.delete_item(self.input).await?;
+            pub async fn run(self) -> Result<(), ::raiden::RaidenError> {
+                let policy: ::raiden::RetryPolicy = self.policy.into();
+                let client = self.client;
+                let input = self.input;
+                policy.retry_if(move || {
+                    let client = client.clone();
+                    let input = input.clone();
+                    async {
+                        #builder_name::inner_run(client, input).await
+                    }
+                }, self.condition).await
+            }
+
+            async fn inner_run(client: ::raiden::DynamoDbClient, input: ::raiden::DeleteItemInput) -> Result<(), ::raiden::RaidenError> {
+                let _res = client.delete_item(input).await?;
                 Ok(())
             }
         }
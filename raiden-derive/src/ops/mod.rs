@@ -6,6 +6,8 @@ mod scan;
 mod update;
 mod shared;
 mod batch_get;
+mod batch_write;
+mod transact_get;
 mod transact_write;
 
 pub(crate) use delete::*;
@@ -15,6 +17,8 @@ pub(crate) use query::*;
 pub(crate) use scan::*;
 pub(crate) use shared::*;
 pub(crate) use batch_get::*;
+pub(crate) use batch_write::*;
+pub(crate) use transact_get::*;
 pub(crate) use transact_write::*;
 pub(crate) use update::*;
 
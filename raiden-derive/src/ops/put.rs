@@ -55,6 +55,7 @@ pub(crate) fn expand_put_item(
             let ident = &f.ident.clone().unwrap();
             let renamed = crate::finder::find_rename_value(&f.attrs);
             let attr_key = create_renamed(ident.to_string(), renamed, rename_all_type);
+            let into_attr = crate::attribute::expand_into_attr(f, quote! { item.#ident.clone() });
             if crate::finder::include_unary_attr(&f.attrs, "uuid") {
                 quote! {
                     let id = #struct_name::gen();
@@ -69,7 +70,7 @@ pub(crate) fn expand_put_item(
                 }
             } else {
                 quote! {
-                    let value = item.#ident.clone().into_attr();
+                    let value = #into_attr;
                     if !::raiden::is_attr_value_empty(&value) {
                         input_item.insert(
                             #attr_key.to_string(),
@@ -154,6 +155,8 @@ pub(crate) fn expand_put_item(
                     client: &self.client,
                     input,
                     item: output_item,
+                    policy: self.retry_condition.strategy.policy(),
+                    condition: &self.retry_condition,
                 }
             }
         }
@@ -162,16 +165,18 @@ pub(crate) fn expand_put_item(
             pub client: &'a ::raiden::DynamoDbClient,
             pub input: ::raiden::PutItemInput,
             pub item: #item_output_name,
+            pub policy: ::raiden::Policy,
+            pub condition: &'a ::raiden::retry::RetryCondition,
         }
 
         impl<'a> #builder_name<'a> {
 
-            fn raw_input(mut self, input: ::raiden::PutItemInput) -> Self {
+            pub fn raw_input(mut self, input: ::raiden::PutItemInput) -> Self {
                 self.input = input;
                 self
             }
 
-            fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
+            pub fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
                 let (cond_str, attr_names, attr_values) = cond.build();
                 if !attr_names.is_empty() {
                     self.input.expression_attribute_names = Some(attr_names);
@@ -183,13 +188,26 @@ pub(crate) fn expand_put_item(
                 self
             }
 
-            async fn run(self) -> Result<::raiden::put::PutOutput<#item_output_name>, ::raiden::RaidenError> {
-                let res = self.client.put_item(self.input).await?;
+            pub async fn run(self) -> Result<::raiden::put::PutOutput<#item_output_name>, ::raiden::RaidenError> {
+                let policy: ::raiden::RetryPolicy = self.policy.into();
+                let client = self.client;
+                let input = self.input;
+                let res = policy.retry_if(move || {
+                    let client = client.clone();
+                    let input = input.clone();
+                    async {
+                        #builder_name::inner_run(client, input).await
+                    }
+                }, self.condition).await?;
                 Ok(::raiden::put::PutOutput {
                     item: self.item,
                     consumed_capacity: res.consumed_capacity,
                 })
             }
+
+            async fn inner_run(client: ::raiden::DynamoDbClient, input: ::raiden::PutItemInput) -> Result<::raiden::PutItemOutput, ::raiden::RaidenError> {
+                Ok(client.put_item(input).await?)
+            }
         }
     }
 }
@@ -1,6 +1,8 @@
 use quote::*;
 
 pub(crate) fn expand_query(
+    partition_key: &(proc_macro2::Ident, syn::Type),
+    sort_key: &Option<(proc_macro2::Ident, syn::Type)>,
     struct_name: &proc_macro2::Ident,
     fields: &syn::FieldsNamed,
     rename_all_type: crate::rename::RenameAllType,
@@ -10,9 +12,28 @@ pub(crate) fn expand_query(
     let builder_name = format_ident!("{}QueryBuilder", struct_name);
 
     let key_condition_token_name = format_ident!("{}KeyConditionToken", struct_name);
+    let condition_token_name = format_ident!("{}ConditionToken", struct_name);
 
     let from_item = super::expand_attr_to_item(&format_ident!("res_item"), fields, rename_all_type);
 
+    let (partition_key_ident, partition_key_type) = partition_key;
+    let sort_key_fn = if let Some((sort_key_ident, sort_key_type)) = sort_key {
+        quote! {
+            // e.g. `.sort_key(1..=3)`, `.sort_key(..3)` or `.sort_key(::raiden::Prefix("2021-"))`
+            // `a..b` is only for integer sort keys, use `a..=b` for strings, binaries and times.
+            // An empty range, e.g. `3..3`, makes `run` return `RaidenError::Validation`.
+            pub fn sort_key(mut self, range: impl ::raiden::IntoSortKeyRange<#sort_key_type>) -> Self {
+                let attr = ::raiden::DocumentPath::new(stringify!(#sort_key_ident));
+                let range = range.into_sort_key_range();
+                self.empty_sort_key_range = range.is_none();
+                self.sort_key_condition = range.map(|range| ::raiden::KeyCond::Cmp(attr, range));
+                self
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     quote! {
         pub trait #trait_name {
             fn query(&self) -> #builder_name;
@@ -22,7 +43,13 @@ pub(crate) fn expand_query(
             pub client: &'a ::raiden::DynamoDbClient,
             pub input: ::raiden::QueryInput,
             pub next_token: Option<::raiden::NextToken>,
-            pub limit: Option<i64>
+            pub limit: Option<i64>,
+            pub policy: ::raiden::Policy,
+            pub condition: &'a ::raiden::retry::RetryCondition,
+            pub value_ids: ::raiden::ValueIdContext,
+            pub partition_key_condition: Option<::raiden::KeyCond>,
+            pub sort_key_condition: Option<::raiden::KeyCond>,
+            pub empty_sort_key_range: bool,
         }
 
         impl #trait_name for #client_name {
@@ -30,7 +57,6 @@ pub(crate) fn expand_query(
             #![allow(clippy::field_reassign_with_default)]
             fn query(&self) -> #builder_name {
                 let mut input = ::raiden::QueryInput::default();
-                // input.filter_expression = Some("num < :value1".to_owned());
                 input.table_name = self.table_name();
                 input.projection_expression = self.projection_expression.clone();
                 input.expression_attribute_names = self.attribute_names.clone();
@@ -39,63 +65,130 @@ pub(crate) fn expand_query(
                     input,
                     next_token: None,
                     limit: None,
+                    policy: self.retry_condition.strategy.policy(),
+                    condition: &self.retry_condition,
+                    value_ids: ::raiden::ValueIdContext::new(),
+                    partition_key_condition: None,
+                    sort_key_condition: None,
+                    empty_sort_key_range: false,
                 }
             }
         }
 
         impl<'a> #builder_name<'a> {
-            fn index(mut self, index: impl Into<String>) -> Self {
+            pub fn index(mut self, index: impl Into<String>) -> Self {
                 self.input.index_name = Some(index.into());
                 self
             }
 
-            fn consistent(mut self) -> Self {
+            pub fn consistent(mut self) -> Self {
                 self.input.consistent_read = Some(true);
                 self
             }
 
-            fn next_token(mut self, token: ::raiden::NextToken) -> Self {
+            pub fn next_token(mut self, token: ::raiden::NextToken) -> Self {
                 self.next_token = Some(token);
                 self
             }
 
-            fn desc(mut self) -> Self {
+            pub fn desc(mut self) -> Self {
                 self.input.scan_index_forward = Some(false);
                 self
             }
 
-            fn asc(mut self) -> Self {
+            pub fn asc(mut self) -> Self {
                 self.input.scan_index_forward = Some(true);
                 self
             }
 
-            fn limit(mut self, limit: usize) -> Self {
+            pub fn limit(mut self, limit: usize) -> Self {
                 self.limit = Some(limit as i64);
                 self
             }
 
-            fn key_condition(mut self, cond: impl ::raiden::key_condition::KeyConditionBuilder<#key_condition_token_name>) -> Self {
-                let (cond_str, attr_names, attr_values) = cond.build();
+            // Can not be used with `partition` or `sort_key`, otherwise `run` returns `RaidenError::Validation`.
+            pub fn key_condition(mut self, cond: impl ::raiden::key_condition::KeyConditionBuilder<#key_condition_token_name>) -> Self {
+                let (cond_str, attr_names, attr_values) = cond.build_with(&mut self.value_ids);
+                if !attr_names.is_empty() {
+                    let names = self.input.expression_attribute_names.take().unwrap_or_default();
+                    self.input.expression_attribute_names = Some(::raiden::merge_map(names, attr_names));
+                }
                 if !attr_values.is_empty() {
-                    self.input.expression_attribute_values = Some(attr_values);
+                    let values = self.input.expression_attribute_values.take().unwrap_or_default();
+                    self.input.expression_attribute_values = Some(::raiden::merge_map(values, attr_values));
                 }
                 self.input.key_condition_expression = Some(cond_str);
                 self
             }
 
-            async fn run(mut self) -> Result<::raiden::query::QueryOutput<#struct_name>, ::raiden::RaidenError> {
-                if let Some(token) = self.next_token {
-                    self.input.exclusive_start_key = Some(token.into_attr_values()?);
+            // Queries the partition with the key, in place of `key_condition`.
+            pub fn partition(mut self, pk: impl Into<#partition_key_type>) -> Self {
+                let attr = ::raiden::DocumentPath::new(stringify!(#partition_key_ident));
+                let pk_attr: AttributeValue = pk.into().into_attr();
+                self.partition_key_condition = Some(::raiden::KeyCond::Cmp(attr, ::raiden::KeyConditionTypes::Eq(pk_attr)));
+                self
+            }
+
+            #sort_key_fn
+
+            fn merge_key_conditions(mut self) -> Result<Self, ::raiden::RaidenError> {
+                if self.empty_sort_key_range {
+                    return Err(::raiden::RaidenError::Validation("sort key range is empty".to_owned()));
+                }
+                let cond = match (self.partition_key_condition.take(), self.sort_key_condition.take()) {
+                    (Some(pk), Some(sk)) => ::raiden::KeyCond::And(Box::new(pk), Box::new(sk)),
+                    (Some(cond), None) | (None, Some(cond)) => cond,
+                    (None, None) => return Ok(self),
+                };
+                // The values of the replaced expression would be left unused, which DynamoDB rejects.
+                if self.input.key_condition_expression.is_some() {
+                    return Err(::raiden::RaidenError::Validation("key_condition can not be used with partition or sort_key".to_owned()));
                 }
+                Ok(self.key_condition(#key_condition_token_name::from_parsed(cond)))
+            }
 
-                let mut items: Vec<#struct_name> = vec![];
+            pub fn filter(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
+                let (cond_str, attr_names, attr_values) = cond.build_with(&mut self.value_ids);
+                if !attr_names.is_empty() {
+                    let names = self.input.expression_attribute_names.take().unwrap_or_default();
+                    self.input.expression_attribute_names = Some(::raiden::merge_map(names, attr_names));
+                }
+                if !attr_values.is_empty() {
+                    let values = self.input.expression_attribute_values.take().unwrap_or_default();
+                    self.input.expression_attribute_values = Some(::raiden::merge_map(values, attr_values));
+                }
+                self.input.filter_expression = Some(cond_str);
+                self
+            }
 
-                loop {
-                    if let Some(limit) = self.limit {
-                        self.input.limit = Some(limit);
+            // Returns a stream of pages. Each page is requested lazily with the retry strategy of the client.
+            pub fn pages(self) -> impl ::raiden::page::Stream<Item = Result<::raiden::query::QueryOutput<#struct_name>, ::raiden::RaidenError>> + 'a {
+                let client = self.client;
+                let policy = self.policy;
+                let condition = self.condition;
+                let input = self.merge_key_conditions().and_then(|builder| {
+                    let mut input = builder.input;
+                    input.limit = builder.limit;
+                    match builder.next_token {
+                        Some(token) => token.into_attr_values().map(|key| {
+                            input.exclusive_start_key = Some(key);
+                            input
+                        }),
+                        None => Ok(input),
                     }
+                });
+
+                ::raiden::page::paginate(input, move |mut input: ::raiden::QueryInput| async move {
+                    let retry_policy: ::raiden::RetryPolicy = policy.into();
+                    let res = retry_policy.retry_if(|| {
+                        let client = client.clone();
+                        let input = input.clone();
+                        async move {
+                            client.query(input).await.map_err(::raiden::RaidenError::from)
+                        }
+                    }, condition).await?;
 
-                    let res = self.client.query(self.input.clone()).await?;
+                    let mut items: Vec<#struct_name> = vec![];
                     if let Some(res_items) = res.items {
                         for res_item in res_items.iter() {
                             items.push(#struct_name {
@@ -104,30 +197,50 @@ pub(crate) fn expand_query(
                         }
                     };
 
-                    let scanned = &res.scanned_count.unwrap_or(0);
-
+                    let scanned = res.scanned_count.unwrap_or(0);
                     let mut has_next = true;
-                    if let Some(limit) = self.limit {
+                    if let Some(limit) = input.limit {
                         has_next = limit - scanned > 0;
-                        self.limit = Some(limit - scanned);
+                        input.limit = Some(limit - scanned);
                     }
 
-                    if res.last_evaluated_key.is_none() || !has_next {
-                        let next_token = if res.last_evaluated_key.is_some() {
-                            Some(::raiden::NextToken::from_attr(&res.last_evaluated_key.unwrap()))
-                        } else {
-                            None
-                        };
-                        return Ok(::raiden::query::QueryOutput {
-                            consumed_capacity: res.consumed_capacity,
-                            count: res.count,
-                            items,
-                            next_token,
-                            scanned_count: res.scanned_count,
-                        })
-                    }
-                    self.input.exclusive_start_key = res.last_evaluated_key;
-                }
+                    let next_token = res.last_evaluated_key.as_ref().map(::raiden::NextToken::from_attr);
+                    let next_input = match res.last_evaluated_key {
+                        Some(key) if has_next => {
+                            input.exclusive_start_key = Some(key);
+                            Some(input)
+                        }
+                        _ => None,
+                    };
+
+                    Ok((::raiden::query::QueryOutput {
+                        consumed_capacity: res.consumed_capacity,
+                        count: res.count,
+                        items,
+                        next_token,
+                        scanned_count: res.scanned_count,
+                    }, next_input))
+                })
+            }
+
+            // Returns a stream of items, requesting the next page only when the current one is consumed.
+            pub fn stream(self) -> impl ::raiden::page::Stream<Item = Result<#struct_name, ::raiden::RaidenError>> + 'a {
+                ::raiden::page::flatten_pages(self.pages(), |page: ::raiden::query::QueryOutput<#struct_name>| page.items)
+            }
+
+            pub async fn run(self) -> Result<::raiden::query::QueryOutput<#struct_name>, ::raiden::RaidenError> {
+                let init = ::raiden::query::QueryOutput {
+                    consumed_capacity: None,
+                    count: None,
+                    items: vec![],
+                    next_token: None,
+                    scanned_count: None,
+                };
+                ::raiden::page::fold_pages(self.pages(), init, |mut acc, mut page| {
+                    acc.items.append(&mut page.items);
+                    page.items = acc.items;
+                    page
+                }).await
             }
         }
     }
@@ -8,6 +8,7 @@ pub(crate) fn expand_scan(
     let trait_name = format_ident!("{}Scan", struct_name);
     let client_name = format_ident!("{}Client", struct_name);
     let builder_name = format_ident!("{}ScanBuilder", struct_name);
+    let condition_token_name = format_ident!("{}ConditionToken", struct_name);
 
     let from_item = super::expand_attr_to_item(&format_ident!("res_item"), fields, rename_all_type);
 
@@ -20,7 +21,11 @@ pub(crate) fn expand_scan(
             pub client: &'a ::raiden::DynamoDbClient,
             pub input: ::raiden::ScanInput,
             pub next_token: Option<::raiden::NextToken>,
-            pub limit: Option<i64>
+            pub limit: Option<i64>,
+            pub parallel: Option<i64>,
+            pub concurrency: Option<usize>,
+            pub policy: ::raiden::Policy,
+            pub condition: &'a ::raiden::retry::RetryCondition,
         }
 
         impl #trait_name for #client_name {
@@ -35,44 +40,153 @@ pub(crate) fn expand_scan(
                     input,
                     next_token: None,
                     limit: None,
+                    parallel: None,
+                    concurrency: None,
+                    policy: self.retry_condition.strategy.policy(),
+                    condition: &self.retry_condition,
                 }
             }
         }
 
         impl<'a> #builder_name<'a> {
-            fn index(mut self, index: impl Into<String>) -> Self {
+            pub fn index(mut self, index: impl Into<String>) -> Self {
                 self.input.index_name = Some(index.into());
                 self
             }
 
-            fn consistent(mut self) -> Self {
+            pub fn consistent(mut self) -> Self {
                 self.input.consistent_read = Some(true);
                 self
             }
 
-            fn next_token(mut self, token: ::raiden::NextToken) -> Self {
+            pub fn next_token(mut self, token: ::raiden::NextToken) -> Self {
                 self.next_token = Some(token);
                 self
             }
 
-            fn limit(mut self, limit: usize) -> Self {
+            pub fn limit(mut self, limit: usize) -> Self {
                 self.limit = Some(limit as i64);
                 self
             }
 
-            async fn run(mut self) -> Result<::raiden::scan::ScanOutput<#struct_name>, ::raiden::RaidenError> {
-                if let Some(token) = self.next_token {
-                    self.input.exclusive_start_key = Some(token.into_attr_values()?);
+            pub fn filter(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
+                let (cond_str, attr_names, attr_values) = cond.build();
+                if !attr_names.is_empty() {
+                    let names = self.input.expression_attribute_names.take().unwrap_or_default();
+                    self.input.expression_attribute_names = Some(::raiden::merge_map(names, attr_names));
                 }
+                if !attr_values.is_empty() {
+                    let values = self.input.expression_attribute_values.take().unwrap_or_default();
+                    self.input.expression_attribute_values = Some(::raiden::merge_map(values, attr_values));
+                }
+                self.input.filter_expression = Some(cond_str);
+                self
+            }
+
+            pub fn segment(mut self, segment: usize, total_segments: usize) -> Self {
+                self.input.segment = Some(segment as i64);
+                self.input.total_segments = Some(total_segments as i64);
+                self
+            }
+
+            // Scans `total_segments` segments concurrently, at most `concurrency` at a time, and merges the results.
+            // `limit` is applied to each segment, and `last_evaluated_key` of the merged output is always `None`.
+            // `total_segments` should be 1 to 1,000,000 and can not be used with `next_token`, otherwise `run` returns `RaidenError::Validation`.
+            pub fn parallel(mut self, total_segments: usize) -> Self {
+                self.parallel = Some(total_segments as i64);
+                self
+            }
+
+            // The maximum number of segments of `parallel` which are scanned at the same time.
+            // It is `raiden::scan::DEFAULT_SCAN_CONCURRENCY` by default, and 0 makes `run` return `RaidenError::Validation`.
+            pub fn concurrency(mut self, concurrency: usize) -> Self {
+                self.concurrency = Some(concurrency);
+                self
+            }
 
-                let mut items: Vec<#struct_name> = vec![];
+            // Returns a stream of pages. Each page is requested lazily with the retry strategy of the client.
+            // `parallel` is not applied, use `segment` to stream a part of the table.
+            pub fn pages(self) -> impl ::raiden::page::Stream<Item = Result<::raiden::scan::ScanOutput<#struct_name>, ::raiden::RaidenError>> + 'a {
+                let mut input = self.input;
+                input.limit = self.limit;
+                let input = match self.next_token {
+                    Some(token) => token.into_attr_values().map(|key| {
+                        input.exclusive_start_key = Some(key);
+                        input
+                    }),
+                    None => Ok(input),
+                };
+                #builder_name::paginate(self.client, self.policy, self.condition, input)
+            }
+
+            // Returns a stream of items, requesting the next page only when the current one is consumed.
+            pub fn stream(self) -> impl ::raiden::page::Stream<Item = Result<#struct_name, ::raiden::RaidenError>> + 'a {
+                ::raiden::page::flatten_pages(self.pages(), |page: ::raiden::scan::ScanOutput<#struct_name>| page.items)
+            }
 
-                loop {
-                    if let Some(limit) = self.limit {
-                        self.input.limit = Some(limit);
+            pub async fn run(self) -> Result<::raiden::scan::ScanOutput<#struct_name>, ::raiden::RaidenError> {
+                if let Some(total_segments) = self.parallel {
+                    if !(1..=1_000_000).contains(&total_segments) {
+                        return Err(::raiden::RaidenError::Validation(format!("total segments of parallel scan should be 1 to 1000000, but {}", total_segments)));
+                    }
+                    // Each segment has its own `LastEvaluatedKey`, so a start key can not be shared by all segments.
+                    if self.next_token.is_some() {
+                        return Err(::raiden::RaidenError::Validation("next_token can not be used with parallel scan, use segment instead".to_owned()));
                     }
+                    let concurrency = self.concurrency.unwrap_or(::raiden::scan::DEFAULT_SCAN_CONCURRENCY);
+                    if concurrency == 0 {
+                        return Err(::raiden::RaidenError::Validation("concurrency of parallel scan should be 1 or more".to_owned()));
+                    }
+                    let client = self.client;
+                    let policy = self.policy;
+                    let condition = self.condition;
+                    let mut input = self.input;
+                    input.limit = self.limit;
+                    let input = &input;
+                    let segments = (0..total_segments).map(|segment| {
+                        let mut input = input.clone();
+                        input.segment = Some(segment);
+                        input.total_segments = Some(total_segments);
+                        #builder_name::fold(#builder_name::paginate(client, policy, condition, Ok(input)))
+                    });
+                    return ::raiden::scan::merge_segments(segments, concurrency).await;
+                }
+
+                #builder_name::fold(self.pages()).await
+            }
+
+            async fn fold(pages: impl ::raiden::page::Stream<Item = Result<::raiden::scan::ScanOutput<#struct_name>, ::raiden::RaidenError>>) -> Result<::raiden::scan::ScanOutput<#struct_name>, ::raiden::RaidenError> {
+                let init = ::raiden::scan::ScanOutput {
+                    consumed_capacity: None,
+                    count: None,
+                    items: vec![],
+                    last_evaluated_key: None,
+                    scanned_count: None,
+                };
+                ::raiden::page::fold_pages(pages, init, |mut acc, mut page| {
+                    acc.items.append(&mut page.items);
+                    page.items = acc.items;
+                    page
+                }).await
+            }
+
+            fn paginate(
+                client: &'a ::raiden::DynamoDbClient,
+                policy: ::raiden::Policy,
+                condition: &'a ::raiden::retry::RetryCondition,
+                input: Result<::raiden::ScanInput, ::raiden::RaidenError>,
+            ) -> impl ::raiden::page::Stream<Item = Result<::raiden::scan::ScanOutput<#struct_name>, ::raiden::RaidenError>> + 'a {
+                ::raiden::page::paginate(input, move |mut input: ::raiden::ScanInput| async move {
+                    let retry_policy: ::raiden::RetryPolicy = policy.into();
+                    let res = retry_policy.retry_if(|| {
+                        let client = client.clone();
+                        let input = input.clone();
+                        async move {
+                            client.scan(input).await.map_err(::raiden::RaidenError::from)
+                        }
+                    }, condition).await?;
 
-                    let res = self.client.scan(self.input.clone()).await?;
+                    let mut items: Vec<#struct_name> = vec![];
                     if let Some(res_items) = res.items {
                         for res_item in res_items.into_iter() {
                             items.push(#struct_name {
@@ -81,24 +195,29 @@ pub(crate) fn expand_scan(
                         }
                     };
 
-                    let scanned = &res.scanned_count.unwrap_or(0);
-
+                    let scanned = res.scanned_count.unwrap_or(0);
                     let mut has_next = true;
-                    if let Some(limit) = self.limit {
+                    if let Some(limit) = input.limit {
                         has_next = limit - scanned > 0;
-                        self.limit = Some(limit - scanned);
-                    }
-                    if res.last_evaluated_key.is_none() || !has_next {
-                        return Ok(::raiden::scan::ScanOutput {
-                            consumed_capacity: res.consumed_capacity,
-                            count: res.count,
-                            items,
-                            last_evaluated_key: res.last_evaluated_key,
-                            scanned_count: res.scanned_count,
-                        })
+                        input.limit = Some(limit - scanned);
                     }
-                    self.input.exclusive_start_key = res.last_evaluated_key;
-                }
+
+                    let next_input = match res.last_evaluated_key.clone() {
+                        Some(key) if has_next => {
+                            input.exclusive_start_key = Some(key);
+                            Some(input)
+                        }
+                        _ => None,
+                    };
+
+                    Ok((::raiden::scan::ScanOutput {
+                        consumed_capacity: res.consumed_capacity,
+                        count: res.count,
+                        items,
+                        last_evaluated_key: res.last_evaluated_key,
+                        scanned_count: res.scanned_count,
+                    }, next_input))
+                })
             }
         }
     }
@@ -9,6 +9,7 @@ pub(crate) fn expand_attr_to_item(
         let ident = &f.ident.clone().unwrap();
         let use_default = crate::finder::include_unary_attr(&f.attrs, "use_default");
         let renamed = crate::finder::find_rename_value(&f.attrs);
+        let from_attr = crate::attribute::expand_from_attr(f, quote! { item.cloned() });
         let attr_key  = if let Some(renamed) = renamed {
             renamed
         }  else if rename_all_type != crate::rename::RenameAllType::None {
@@ -23,11 +24,10 @@ pub(crate) fn expand_attr_to_item(
                 if item.is_none() {
                     None
                 } else {
-                    let converted = ::raiden::FromAttribute::from_attr(item.cloned());
-                    if converted.is_err() {
-                        return Err(::raiden::RaidenError::AttributeConvertError{ attr_name: #attr_key.to_string() });
+                    match #from_attr {
+                        Ok(converted) => converted,
+                        Err(source) => return Err(::raiden::RaidenError::AttributeConvertError{ attr_name: #attr_key.to_string(), source }),
                     }
-                    converted.unwrap()
                 }
               },
             }
@@ -39,12 +39,10 @@ pub(crate) fn expand_attr_to_item(
                 if item.is_none() {
                     #ty::default()
                 } else {
-                    let converted = ::raiden::FromAttribute::from_attr(item.cloned());
-                    if converted.is_err() {
-                      // TODO: improve error handling.
-                        return Err(::raiden::RaidenError::AttributeConvertError{ attr_name: #attr_key.to_string() });
+                    match #from_attr {
+                        Ok(converted) => converted,
+                        Err(source) => return Err(::raiden::RaidenError::AttributeConvertError{ attr_name: #attr_key.to_string(), source }),
                     }
-                    converted.unwrap()
                 }
               },
             }
@@ -52,12 +50,10 @@ pub(crate) fn expand_attr_to_item(
             quote! {
                 #ident: {
                   let item = #item_ident.get(#attr_key);
-                  let converted = ::raiden::FromAttribute::from_attr(item.cloned());
-                  if converted.is_err() {
-                    // TODO: improve error handling.
-                      return Err(::raiden::RaidenError::AttributeConvertError{ attr_name: #attr_key.to_string() });
+                  match #from_attr {
+                      Ok(converted) => converted,
+                      Err(source) => return Err(::raiden::RaidenError::AttributeConvertError{ attr_name: #attr_key.to_string(), source }),
                   }
-                  converted.unwrap()
                 },
               }
         }
@@ -0,0 +1,109 @@
+use proc_macro2::*;
+use quote::*;
+use syn::*;
+
+use crate::rename::*;
+
+pub(crate) fn expand_transact_get(
+    struct_name: &Ident,
+    partition_key: &(Ident, Type),
+    sort_key: &Option<(Ident, Type)>,
+    fields: &FieldsNamed,
+    rename_all_type: RenameAllType,
+    table_name: &str,
+) -> TokenStream {
+    let get_builder = format_ident!("{}TransactGetItemBuilder", struct_name);
+    let (partition_key_ident, partition_key_type) = partition_key;
+
+    let insertion_attribute_name = fields.named.iter().map(|f| {
+        let ident = &f.ident.clone().unwrap();
+        let renamed = crate::finder::find_rename_value(&f.attrs);
+        let result = create_renamed(ident.to_string(), renamed, rename_all_type);
+        quote! {
+            names.insert(
+                format!("#{}", #result.clone()),
+                #result.to_string(),
+            );
+        }
+    });
+
+    let builder_init = quote! {
+        let names = {
+            let mut names: ::raiden::AttributeNames = std::collections::HashMap::new();
+            #(#insertion_attribute_name)*
+            names
+        };
+        let projection_expression = Some(names.keys().map(|v| v.to_string()).collect::<Vec<String>>().join(", "));
+        let input = ::raiden::Get {
+            key: key_set,
+            projection_expression,
+            expression_attribute_names: Some(names),
+            ..::raiden::Get::default()
+        };
+
+        #get_builder {
+            input,
+            table_name: #table_name.to_owned(),
+            table_prefix: "".to_owned(),
+            table_suffix: "".to_owned(),
+        }
+    };
+
+    let transact_get_fn = if let Some(sort_key) = sort_key {
+        let (sort_key_ident, sort_key_type) = sort_key;
+        quote! {
+            pub fn transact_get(pk: impl Into<#partition_key_type>, sk: impl Into<#sort_key_type>) -> #get_builder {
+                let pk_attr: AttributeValue = pk.into().into_attr();
+                let sk_attr: AttributeValue = sk.into().into_attr();
+                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
+                key_set.insert(stringify!(#partition_key_ident).to_owned(), pk_attr);
+                key_set.insert(stringify!(#sort_key_ident).to_owned(), sk_attr);
+
+                #builder_init
+            }
+        }
+    } else {
+        quote! {
+            pub fn transact_get(key: impl Into<#partition_key_type>) -> #get_builder {
+                let key_attr: AttributeValue = key.into().into_attr();
+                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
+                key_set.insert(stringify!(#partition_key_ident).to_owned(), key_attr);
+
+                #builder_init
+            }
+        }
+    };
+
+    quote! {
+        impl #struct_name {
+            #transact_get_fn
+        }
+
+        pub struct #get_builder {
+            pub table_name: String,
+            pub table_prefix: String,
+            pub table_suffix: String,
+            pub input: ::raiden::Get,
+        }
+
+        impl ::raiden::TransactGetBuilder for #get_builder {
+            fn build(self) -> ::raiden::Get {
+                let mut input = self.input;
+                input.table_name = format!("{}{}{}", self.table_prefix, self.table_name, self.table_suffix);
+                input
+            }
+        }
+
+        impl #get_builder {
+            pub fn table_prefix(mut self, s: impl Into<String>) -> Self {
+                self.table_prefix = s.into();
+                self
+            }
+
+            pub fn table_suffix(mut self, s: impl Into<String>) -> Self {
+                self.table_suffix = s.into();
+                self
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::rename::*;
 pub(crate) fn expand_transact_write(
     struct_name: &Ident,
     partition_key: &(Ident, Type),
-    _sort_key: &Option<(Ident, Type)>, // TODO: Support sort key
+    sort_key: &Option<(Ident, Type)>,
     fields: &FieldsNamed,
     attr_enum_name: &Ident,
     rename_all_type: RenameAllType,
@@ -21,6 +21,29 @@ pub(crate) fn expand_transact_write(
     let condition_token_name = format_ident!("{}ConditionToken", struct_name);
     let (partition_key_ident, partition_key_type) = partition_key;
 
+    let (key_params, key_set_init) = if let Some(sort_key) = sort_key {
+        let (sort_key_ident, sort_key_type) = sort_key;
+        (
+            quote! { pk: impl Into<#partition_key_type>, sk: impl Into<#sort_key_type> },
+            quote! {
+                let pk_attr: AttributeValue = pk.into().into_attr();
+                let sk_attr: AttributeValue = sk.into().into_attr();
+                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
+                key_set.insert(stringify!(#partition_key_ident).to_owned(), pk_attr);
+                key_set.insert(stringify!(#sort_key_ident).to_owned(), sk_attr);
+            },
+        )
+    } else {
+        (
+            quote! { key: impl Into<#partition_key_type> },
+            quote! {
+                let key_attr: AttributeValue = key.into().into_attr();
+                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
+                key_set.insert(stringify!(#partition_key_ident).to_owned(), key_attr);
+            },
+        )
+    };
+
     // let (sort_key_ident, sort_key_type) = sort_key;
     // let output_values = fields.named.iter().map(|f| {
     //     let ident = &f.ident.clone().unwrap();
@@ -42,6 +65,7 @@ pub(crate) fn expand_transact_write(
             let ident = &f.ident.clone().unwrap();
             let renamed = crate::finder::find_rename_value(&f.attrs);
             let attr_key = create_renamed(ident.to_string(), renamed, rename_all_type);
+            let into_attr = crate::attribute::expand_into_attr(f, quote! { item.#ident });
             if crate::finder::include_unary_attr(&f.attrs, "uuid") {
                 quote! {
                     let id = #struct_name::gen();
@@ -56,7 +80,7 @@ pub(crate) fn expand_transact_write(
                 }
             } else {
                 quote! {
-                    let value = item.#ident.into_attr();
+                    let value = #into_attr;
                     if !::raiden::is_attr_value_empty(&value) {
                         input_item.insert(
                             #attr_key.to_string(),
@@ -96,12 +120,9 @@ pub(crate) fn expand_transact_write(
                 }
             }
 
-            // TODO: Support sort key
-            pub fn condition_check(key: impl Into<#partition_key_type>) -> #condition_check_builder {
+            pub fn condition_check(#key_params) -> #condition_check_builder {
                 let mut input = ::raiden::ConditionCheck::default();
-                let key_attr: AttributeValue = key.into().into_attr();
-                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
-                key_set.insert(stringify!(#partition_key_ident).to_owned(), key_attr);
+                #key_set_init
                 input.key = key_set;
                 #condition_check_builder {
                     input,
@@ -112,12 +133,9 @@ pub(crate) fn expand_transact_write(
                 }
             }
 
-            // TODO: Support sort key
-            pub fn delete(key: impl Into<#partition_key_type>) -> #delete_builder {
+            pub fn delete(#key_params) -> #delete_builder {
                 let mut input = ::raiden::Delete::default();
-                let key_attr: AttributeValue = key.into().into_attr();
-                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
-                key_set.insert(stringify!(#partition_key_ident).to_owned(), key_attr);
+                #key_set_init
                 input.key = key_set;
                 #delete_builder {
                     input,
@@ -128,13 +146,10 @@ pub(crate) fn expand_transact_write(
                 }
             }
 
-            // TODO: Support sort key
-            pub fn update(key: impl Into<#partition_key_type>) -> #update_builder {
+            pub fn update(#key_params) -> #update_builder {
                 let mut input = ::raiden::Update::default();
 
-                let key_attr: AttributeValue = key.into().into_attr();
-                let mut key_set: std::collections::HashMap<String, AttributeValue> = std::collections::HashMap::new();
-                key_set.insert(stringify!(#partition_key_ident).to_owned(), key_attr);
+                #key_set_init
                 input.key = key_set;
 
                 #update_builder {
@@ -147,6 +162,7 @@ pub(crate) fn expand_transact_write(
                     set_items: vec![],
                     remove_items: vec![],
                     delete_items: vec![],
+                    value_ids: ::raiden::ValueIdContext::new(),
                 }
             }
         }
@@ -168,17 +184,17 @@ pub(crate) fn expand_transact_write(
 
         impl #put_builder {
 
-            fn table_prefix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_prefix(mut self, s: impl Into<String>) -> Self {
                 self.table_prefix = s.into();
                 self
             }
 
-            fn table_suffix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_suffix(mut self, s: impl Into<String>) -> Self {
                 self.table_suffix = s.into();
                 self
             }
 
-            fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
+            pub fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
                 let (cond_str, attr_names, attr_values) = cond.build();
                 if !attr_names.is_empty() {
                     self.input.expression_attribute_names = Some(attr_names);
@@ -199,8 +215,9 @@ pub(crate) fn expand_transact_write(
 
             pub add_items: Vec<(String, ::raiden::AttributeNames, ::raiden::AttributeValues)>,
             pub set_items: Vec<::raiden::update_expression::SetOrRemove>,
-            pub remove_items: Vec<#attr_enum_name>,
-            pub delete_items: Vec<(#attr_enum_name, ::raiden::AttributeValue)>,
+            pub remove_items: Vec<::raiden::AttrPath<#attr_enum_name>>,
+            pub delete_items: Vec<(::raiden::AttrPath<#attr_enum_name>, ::raiden::AttributeValue)>,
+            pub value_ids: ::raiden::ValueIdContext,
         }
 
         impl ::raiden::TransactWriteUpdateBuilder for #update_builder {
@@ -216,12 +233,10 @@ pub(crate) fn expand_transact_write(
                 let remove_items = std::mem::replace(&mut self.remove_items, vec![]);
                 let delete_items = std::mem::replace(&mut self.delete_items, vec![]);
 
-                let mut remove_expressions = remove_items.into_iter().map(|name| {
-                    let placeholder = format!(":value{}", ::raiden::generate_value_id());
-                    let attr_name = format!("#{}", name.into_attr_name());
-                    let val = format!("{}", attr_name);
-                    attr_names.insert(attr_name, name.into_attr_name());
-                    val
+                let mut remove_expressions = remove_items.into_iter().map(|path| {
+                    let path = path.into_document_path();
+                    attr_names = ::raiden::merge_map(std::mem::take(&mut attr_names), path.to_attr_names());
+                    path.to_string()
                 }).collect::<Vec<String>>();
 
                 let mut set_expressions = vec![];
@@ -256,11 +271,12 @@ pub(crate) fn expand_transact_write(
 
 
 
-                let delete_expression = delete_items.into_iter().map(|(name, value)| {
-                    let placeholder = format!(":value{}", ::raiden::generate_value_id());
-                    let attr_name = format!("#{}", name.into_attr_name());
-                    let val = format!("{} {}", attr_name, placeholder);
-                    attr_names.insert(attr_name, name.into_attr_name());
+                let value_ids = &mut self.value_ids;
+                let delete_expression = delete_items.into_iter().map(|(path, value)| {
+                    let placeholder = value_ids.next_placeholder();
+                    let path = path.into_document_path();
+                    let val = format!("{} {}", path.to_string(), placeholder);
+                    attr_names = ::raiden::merge_map(std::mem::take(&mut attr_names), path.to_attr_names());
                     attr_values.insert(placeholder, value);
                     val
                 }).collect::<Vec<_>>().join(", ");
@@ -300,38 +316,55 @@ pub(crate) fn expand_transact_write(
 
         impl #update_builder {
 
-            fn table_prefix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_prefix(mut self, s: impl Into<String>) -> Self {
                 self.table_prefix = s.into();
                 self
             }
 
-            fn table_suffix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_suffix(mut self, s: impl Into<String>) -> Self {
                 self.table_suffix = s.into();
                 self
             }
 
             pub fn add(mut self, add: impl ::raiden::update_expression::UpdateAddExpressionBuilder) -> Self {
-                self.add_items.push(add.build());
+                self.add_items.push(add.build_with(&mut self.value_ids));
                 self
             }
 
             pub fn set(mut self, set: impl ::raiden::update_expression::UpdateSetExpressionBuilder) -> Self {
-                self.set_items.push(set.build());
+                self.set_items.push(set.build_with(&mut self.value_ids));
                 self
             }
 
-            pub fn remove(mut self, attr: #attr_enum_name) -> Self {
-                self.remove_items.push(attr);
+            pub fn remove(mut self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> Self {
+                self.remove_items.push(attr.into_attr_path());
                 self
             }
 
-            pub fn delete(mut self, attr: #attr_enum_name, value: impl ::raiden::IntoAttribute) -> Self {
-                self.delete_items.push((attr, value.into_attr()));
+            // Remove an element of LIST, as `REMOVE #attr[index]`.
+            pub fn remove_index(mut self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>, index: usize) -> Self {
+                self.remove_items.push(attr.into_attr_path().index(index));
+                self
+            }
+
+            // Remove elements of LIST. Every index refers to the list before the update.
+            pub fn remove_indexes(mut self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>, mut indexes: Vec<usize>) -> Self {
+                let path = attr.into_attr_path();
+                indexes.sort_unstable();
+                indexes.dedup();
+                for index in indexes {
+                    self.remove_items.push(path.clone().index(index));
+                }
+                self
+            }
+
+            pub fn delete(mut self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>, value: impl ::raiden::IntoAttribute) -> Self {
+                self.delete_items.push((attr.into_attr_path(), value.into_attr()));
                 self
             }
 
             pub fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
-                let (cond_str, attr_names, attr_values) = cond.build();
+                let (cond_str, attr_names, attr_values) = cond.build_with(&mut self.value_ids);
                 if !attr_names.is_empty() {
                     self.input.expression_attribute_names = Some(attr_names);
                 }
@@ -359,17 +392,17 @@ pub(crate) fn expand_transact_write(
         }
 
         impl #delete_builder {
-            fn table_prefix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_prefix(mut self, s: impl Into<String>) -> Self {
                 self.table_prefix = s.into();
                 self
             }
 
-            fn table_suffix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_suffix(mut self, s: impl Into<String>) -> Self {
                 self.table_suffix = s.into();
                 self
             }
 
-            fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
+            pub fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
                 let (cond_str, attr_names, attr_values) = cond.build();
                 if !attr_names.is_empty() {
                     self.input.expression_attribute_names = Some(attr_names);
@@ -398,17 +431,17 @@ pub(crate) fn expand_transact_write(
         }
 
         impl #condition_check_builder {
-            fn table_prefix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_prefix(mut self, s: impl Into<String>) -> Self {
                 self.table_prefix = s.into();
                 self
             }
 
-            fn table_suffix(mut self, s: impl Into<String>) -> Self {
+            pub fn table_suffix(mut self, s: impl Into<String>) -> Self {
                 self.table_suffix = s.into();
                 self
             }
 
-            fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
+            pub fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
                 let (cond_str, attr_names, attr_values) = cond.build();
                 if !attr_names.is_empty() {
                     self.input.expression_attribute_names = Some(attr_names);
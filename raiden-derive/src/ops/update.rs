@@ -43,6 +43,9 @@ pub(crate) fn expand_update_item(
                         add_items: vec![],
                         remove_items: vec![],
                         delete_items: vec![],
+                        policy: self.retry_condition.strategy.policy(),
+                        condition: &self.retry_condition,
+                        value_ids: ::raiden::ValueIdContext::new(),
                     }
                 }
             }
@@ -68,38 +71,62 @@ pub(crate) fn expand_update_item(
                         add_items: vec![],
                         remove_items: vec![],
                         delete_items: vec![],
+                        policy: self.retry_condition.strategy.policy(),
+                        condition: &self.retry_condition,
+                        value_ids: ::raiden::ValueIdContext::new(),
                     }
                 }
             }
         }
     };
 
+    let field_updates = fields
+        .named
+        .iter()
+        .map(|f| (f, crate::attribute::expand_field_accessor(f, rename_all_type)))
+        .map(|(f, (name, func_name))| {
+            // Fields named `set`, `add` or `delete` get `set_field()` and so on, not to collide with the untyped ones.
+            let func_name = if matches!(func_name.to_string().as_str(), "set" | "add" | "delete") {
+                format_ident!("{}_field", func_name)
+            } else {
+                func_name
+            };
+            let ty = crate::attribute::attr_type(f);
+            quote! {
+                pub fn #func_name(&self) -> ::raiden::update_expression::FieldUpdate<#attr_enum_name, #ty> {
+                    ::raiden::update_expression::FieldUpdate::new(#attr_enum_name::#name)
+                }
+            }
+        });
+
     quote! {
         #[derive(Debug, Clone, PartialEq)]
         pub struct #item_output_name {
             // #(#output_fields)*
         }
 
-        struct #update_expression_name;
+        pub struct #update_expression_name;
 
         impl #struct_name {
-            fn update_expression() -> #update_expression_name {
+            pub fn update_expression() -> #update_expression_name {
                 #update_expression_name
             }
         }
 
         impl #update_expression_name {
-            fn set(&self, attr: #attr_enum_name) -> ::raiden::update_expression::Set<#attr_enum_name> {
+            pub fn set(&self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ::raiden::update_expression::Set<#attr_enum_name> {
                 ::raiden::update_expression::Set::new(attr)
             }
 
-            fn add(&self, attr: #attr_enum_name) -> ::raiden::update_expression::Add<#attr_enum_name> {
+            pub fn add(&self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ::raiden::update_expression::Add<#attr_enum_name> {
                 ::raiden::update_expression::Add::new(attr)
             }
 
-            fn delete(&self, attr: #attr_enum_name) -> ::raiden::update_expression::Delete<#attr_enum_name> {
+            pub fn delete(&self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> ::raiden::update_expression::Delete<#attr_enum_name> {
                 ::raiden::update_expression::Delete::new(attr)
             }
+
+            #(#field_updates)*
         }
 
         #client_trait
@@ -109,8 +136,11 @@ pub(crate) fn expand_update_item(
             pub input: ::raiden::UpdateItemInput,
             pub add_items: Vec<(String, ::raiden::AttributeNames, ::raiden::AttributeValues)>,
             pub set_items: Vec<::raiden::update_expression::SetOrRemove>,
-            pub remove_items: Vec<#attr_enum_name>,
+            pub remove_items: Vec<::raiden::AttrPath<#attr_enum_name>>,
             pub delete_items: Vec<(String, ::raiden::AttributeNames, ::raiden::AttributeValues)>,
+            pub policy: ::raiden::Policy,
+            pub condition: &'a ::raiden::retry::RetryCondition,
+            pub value_ids: ::raiden::ValueIdContext,
         }
 
         impl<'a> #builder_name<'a> {
@@ -120,22 +150,39 @@ pub(crate) fn expand_update_item(
             }
 
             pub fn add(mut self, add: impl ::raiden::update_expression::UpdateAddExpressionBuilder) -> Self {
-                self.add_items.push(add.build());
+                self.add_items.push(add.build_with(&mut self.value_ids));
                 self
             }
 
             pub fn set(mut self, set: impl ::raiden::update_expression::UpdateSetExpressionBuilder) -> Self {
-                self.set_items.push(set.build());
+                self.set_items.push(set.build_with(&mut self.value_ids));
+                self
+            }
+
+            pub fn remove(mut self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>) -> Self {
+                self.remove_items.push(attr.into_attr_path());
                 self
             }
 
-            pub fn remove(mut self, attr: #attr_enum_name) -> Self {
-                self.remove_items.push(attr);
+            // Remove an element of LIST, as `REMOVE #attr[index]`.
+            pub fn remove_index(mut self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>, index: usize) -> Self {
+                self.remove_items.push(attr.into_attr_path().index(index));
+                self
+            }
+
+            // Remove elements of LIST. Every index refers to the list before the update.
+            pub fn remove_indexes(mut self, attr: impl ::raiden::IntoAttrPath<#attr_enum_name>, mut indexes: Vec<usize>) -> Self {
+                let path = attr.into_attr_path();
+                indexes.sort_unstable();
+                indexes.dedup();
+                for index in indexes {
+                    self.remove_items.push(path.clone().index(index));
+                }
                 self
             }
 
             pub fn delete(mut self, set: impl ::raiden::update_expression::UpdateDeleteExpressionBuilder) -> Self {
-                self.delete_items.push(set.build());
+                self.delete_items.push(set.build_with(&mut self.value_ids));
                 self
             }
 
@@ -152,7 +199,7 @@ pub(crate) fn expand_update_item(
             }
 
             pub fn condition(mut self, cond: impl ::raiden::condition::ConditionBuilder<#condition_token_name>) -> Self {
-                let (cond_str, attr_names, attr_values) = cond.build();
+                let (cond_str, attr_names, attr_values) = cond.build_with(&mut self.value_ids);
                 if !attr_names.is_empty() {
                     self.input.expression_attribute_names = Some(attr_names);
                 }
@@ -172,12 +219,10 @@ pub(crate) fn expand_update_item(
                 let remove_items = std::mem::replace(&mut self.remove_items, vec![]);
                 let delete_items = std::mem::replace(&mut self.delete_items, vec![]);
 
-                let mut remove_expressions = remove_items.into_iter().map(|name| {
-                    let placeholder = format!(":value{}", ::raiden::generate_value_id());
-                    let attr_name = format!("#{}", name.into_attr_name());
-                    let val = format!("{}", attr_name);
-                    attr_names.insert(attr_name, name.into_attr_name());
-                    val
+                let mut remove_expressions = remove_items.into_iter().map(|path| {
+                    let path = path.into_document_path();
+                    attr_names = ::raiden::merge_map(std::mem::take(&mut attr_names), path.to_attr_names());
+                    path.to_string()
                 }).collect::<Vec<String>>();
 
                 let mut set_expressions = vec![];
@@ -267,7 +312,16 @@ pub(crate) fn expand_update_item(
                 }
 
                 let has_return_values = self.input.return_values.is_some();
-                let res = self.client.update_item(self.input).await?;
+                let policy: ::raiden::RetryPolicy = self.policy.into();
+                let client = self.client;
+                let input = self.input;
+                let res = policy.retry_if(move || {
+                    let client = client.clone();
+                    let input = input.clone();
+                    async {
+                        #builder_name::inner_run(client, input).await
+                    }
+                }, self.condition).await?;
 
                 let item = if has_return_values {
                     let res_item = &res.attributes.unwrap();
@@ -284,6 +338,10 @@ pub(crate) fn expand_update_item(
                     item_collection_metrics: res.item_collection_metrics,
                 })
             }
+
+            async fn inner_run(client: ::raiden::DynamoDbClient, input: ::raiden::UpdateItemInput) -> Result<::raiden::UpdateItemOutput, ::raiden::RaidenError> {
+                Ok(client.update_item(input).await?)
+            }
         }
     }
 }
@@ -65,3 +65,42 @@ pub fn create_renamed(
         basename
     }
 }
+
+// Same as `rename`, but for an enum variant which is in PascalCase.
+pub fn rename_variant(t: RenameAllType, base: String) -> String {
+    match t {
+        crate::rename::RenameAllType::LowerCase => {
+            ident_case::RenameRule::LowerCase.apply_to_variant(base)
+        }
+        crate::rename::RenameAllType::CamelCase => {
+            ident_case::RenameRule::CamelCase.apply_to_variant(base)
+        }
+        crate::rename::RenameAllType::PascalCase => {
+            ident_case::RenameRule::PascalCase.apply_to_variant(base)
+        }
+        crate::rename::RenameAllType::SnakeCase => {
+            ident_case::RenameRule::SnakeCase.apply_to_variant(base)
+        }
+        crate::rename::RenameAllType::ScreamingSnakeCase => {
+            ident_case::RenameRule::ScreamingSnakeCase.apply_to_variant(base)
+        }
+        crate::rename::RenameAllType::KebabCase => {
+            ident_case::RenameRule::KebabCase.apply_to_variant(base)
+        }
+        _ => panic!("{} is not supported rename type", base),
+    }
+}
+
+pub fn create_renamed_variant(
+    basename: String,
+    renamed: Option<String>,
+    rename_all_type: RenameAllType,
+) -> String {
+    if let Some(renamed) = renamed {
+        renamed
+    } else if rename_all_type != RenameAllType::None {
+        rename_variant(rename_all_type, basename)
+    } else {
+        basename
+    }
+}
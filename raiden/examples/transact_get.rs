@@ -0,0 +1,29 @@
+use raiden::*;
+
+#[derive(Raiden, Debug)]
+#[raiden(table_name = "user")]
+pub struct User {
+    #[raiden(partition_key)]
+    pub id: String,
+    pub name: String,
+}
+
+fn main() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    async fn example() {
+        let tx = ::raiden::ReadTx::new(Region::Custom {
+            endpoint: "http://localhost:8000".into(),
+            name: "ap-northeast-1".into(),
+        });
+        let res = tx
+            .get(User::transact_get("testId"))
+            .get(User::transact_get("testId2"))
+            .run()
+            .await
+            .unwrap();
+        let user: Option<User> = res.get(0).unwrap();
+        let user2: Option<User> = res.get(1).unwrap();
+        dbg!(user, user2);
+    }
+    rt.block_on(example());
+}
@@ -0,0 +1,163 @@
+// Binary attributes `B` and `BS`, e.g. for compressed blobs and hashes.
+// `Vec<u8>` is stored as a list of numbers, so wrap it with `Binary` or use `bytes::Bytes` instead.
+use super::{
+    AddOperand, AttributeValue, BeginsWithOperand, ContainsOperand, ConversionError, DeleteOperand,
+    FromAttribute, IntoAttribute,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Binary(pub Vec<u8>);
+
+impl Binary {
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for Binary {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Binary {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Binary {
+    fn from(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+}
+
+impl From<&[u8]> for Binary {
+    fn from(v: &[u8]) -> Self {
+        Self(v.to_vec())
+    }
+}
+
+impl From<bytes::Bytes> for Binary {
+    fn from(v: bytes::Bytes) -> Self {
+        Self(v.to_vec())
+    }
+}
+
+impl From<Binary> for Vec<u8> {
+    fn from(v: Binary) -> Self {
+        v.0
+    }
+}
+
+impl From<Binary> for bytes::Bytes {
+    fn from(v: Binary) -> Self {
+        bytes::Bytes::from(v.0)
+    }
+}
+
+macro_rules! default_binary_convertor {
+    ($to: ty) => {
+        impl IntoAttribute for $to {
+            fn into_attr(self) -> AttributeValue {
+                // Empty binary is allowed since 2020/5 as well as empty string.
+                AttributeValue {
+                    b: Some(self.into()),
+                    ..AttributeValue::default()
+                }
+            }
+        }
+
+        impl FromAttribute for $to {
+            fn from_attr(value: Option<AttributeValue>) -> Result<Self, ConversionError> {
+                if value.is_none() {
+                    return Err(ConversionError::ValueIsNone);
+                }
+                let value = value.unwrap();
+                if let Some(true) = value.null {
+                    // See. https://github.com/raiden-rs/raiden/issues/58
+                    return Ok(<$to>::default());
+                }
+                value.b.map(Into::into).ok_or(ConversionError::ValueIsNone)
+            }
+        }
+
+        impl BeginsWithOperand for $to {
+            type Prefix = $to;
+
+            fn prefix_into_attr(prefix: Self::Prefix) -> AttributeValue {
+                prefix.into_attr()
+            }
+        }
+
+        impl IntoAttribute for std::collections::HashSet<$to> {
+            fn into_attr(self) -> AttributeValue {
+                if self.is_empty() {
+                    // See. https://github.com/raiden-rs/raiden/issues/57
+                    //      https://github.com/raiden-rs/raiden-dynamo/issues/64
+                    return AttributeValue::default();
+                }
+                AttributeValue {
+                    bs: Some(self.into_iter().map(Into::into).collect()),
+                    ..AttributeValue::default()
+                }
+            }
+        }
+
+        impl FromAttribute for std::collections::HashSet<$to> {
+            fn from_attr(value: Option<AttributeValue>) -> Result<Self, ConversionError> {
+                if value.is_none() {
+                    return Ok(std::collections::HashSet::new());
+                }
+                let value = value.unwrap();
+                if let Some(true) = value.null {
+                    // See. https://github.com/raiden-rs/raiden/issues/57
+                    return Ok(std::collections::HashSet::new());
+                }
+                let bs = value.bs.ok_or(ConversionError::ValueIsNone)?;
+                Ok(bs.into_iter().map(Into::into).collect())
+            }
+        }
+
+        impl AddOperand for std::collections::HashSet<$to> {}
+
+        impl ContainsOperand for std::collections::HashSet<$to> {
+            type Operand = $to;
+
+            fn operand_into_attr(operand: Self::Operand) -> AttributeValue {
+                operand.into_attr()
+            }
+        }
+
+        impl DeleteOperand for std::collections::HashSet<$to> {}
+    };
+}
+
+default_binary_convertor!(Binary);
+default_binary_convertor!(bytes::Bytes);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_into_attr_and_back() {
+        let value = Binary(vec![0, 1]).into_attr();
+        assert_eq!(value.b, Some(bytes::Bytes::from(vec![0, 1])));
+        assert_eq!(Binary::from_attr(Some(value)).unwrap(), Binary(vec![0, 1]));
+
+        let mut hashes = std::collections::HashSet::new();
+        hashes.insert(bytes::Bytes::from_static(b"ab"));
+        let value = hashes.clone().into_attr();
+        assert_eq!(value.bs, Some(vec![bytes::Bytes::from_static(b"ab")]));
+        assert_eq!(
+            std::collections::HashSet::<bytes::Bytes>::from_attr(Some(value)).unwrap(),
+            hashes
+        );
+
+        // `Vec<u8>` is still a list of numbers.
+        assert!(vec![0u8, 1].into_attr().l.is_some());
+    }
+}
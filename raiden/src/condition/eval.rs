@@ -0,0 +1,350 @@
+// Evaluates conditions against an item in memory, following the semantics of DynamoDB.
+// See. https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.OperatorsAndFunctions.html
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use super::{
+    AttrOrPlaceholder, AttributeType, AttributeValue, AttributeValues, Cond,
+    ConditionComparisonExpression, ConditionFunctionExpression, DocumentPath, PathElement,
+};
+
+impl Cond {
+    // Returns whether the item satisfies the condition.
+    // As DynamoDB does, a comparison with a missing attribute or with a value of another type is false,
+    // except `<>`, which is true for them.
+    pub fn evaluate(&self, item: &AttributeValues) -> bool {
+        match self {
+            Self::Func(func) => func.evaluate(item),
+            Self::Cmp(cmp) => cmp.evaluate(item),
+            Self::Not(cond) => !cond.evaluate(item),
+            Self::And(left, right) => left.evaluate(item) && right.evaluate(item),
+            Self::Or(left, right) => left.evaluate(item) || right.evaluate(item),
+        }
+    }
+}
+
+impl ConditionFunctionExpression {
+    fn evaluate(&self, item: &AttributeValues) -> bool {
+        match self {
+            Self::AttributeExists(path) => resolve_path(item, path).is_some(),
+            Self::AttributeNotExists(path) => resolve_path(item, path).is_none(),
+            Self::AttributeType(path, t) => {
+                resolve_path(item, path).and_then(attribute_type) == Some(t.clone())
+            }
+            Self::BeginsWith(path, prefix) => match resolve_path(item, path) {
+                Some(value) => begins_with(value, prefix),
+                None => false,
+            },
+            Self::Contains(path, operand) => match resolve_path(item, path) {
+                Some(value) => contains(value, operand),
+                None => false,
+            },
+            // `size` is not a condition by itself.
+            Self::Size(_) => false,
+        }
+    }
+}
+
+impl ConditionComparisonExpression {
+    fn evaluate(&self, item: &AttributeValues) -> bool {
+        let ordered = |left: &AttrOrPlaceholder,
+                       right: &AttrOrPlaceholder,
+                       f: fn(Ordering) -> bool| {
+            match (resolve_operand(item, left), resolve_operand(item, right)) {
+                (Some(left), Some(right)) => {
+                    matches!(compare_values(&left, &right), Some(o) if f(o))
+                }
+                _ => false,
+            }
+        };
+        let equals = |left: &AttrOrPlaceholder, right: &AttrOrPlaceholder| match (
+            resolve_operand(item, left),
+            resolve_operand(item, right),
+        ) {
+            (Some(left), Some(right)) => equal_values(&left, &right),
+            _ => false,
+        };
+        match self {
+            Self::Eq(left, right) => equals(left, right),
+            Self::Ne(left, right) => !equals(left, right),
+            Self::Lt(left, right) => ordered(left, right, |o| o == Ordering::Less),
+            Self::Le(left, right) => ordered(left, right, |o| o != Ordering::Greater),
+            Self::Gt(left, right) => ordered(left, right, |o| o == Ordering::Greater),
+            Self::Ge(left, right) => ordered(left, right, |o| o != Ordering::Less),
+            Self::Between(left, lower, upper) => {
+                ordered(left, lower, |o| o != Ordering::Less)
+                    && ordered(left, upper, |o| o != Ordering::Greater)
+            }
+            Self::In(left, list) => list.iter().any(|right| equals(left, right)),
+        }
+    }
+}
+
+// A string contains a substring, and a set or a list contains an element of the same type.
+fn contains(value: &AttributeValue, operand: &AttributeValue) -> bool {
+    if let (Some(value), Some(s)) = (value.s.as_ref(), operand.s.as_ref()) {
+        value.contains(s.as_str())
+    } else if let (Some(set), Some(s)) = (value.ss.as_ref(), operand.s.as_ref()) {
+        set.contains(s)
+    } else if let (Some(set), Some(n)) = (value.ns.as_ref(), operand.n.as_ref()) {
+        set.iter()
+            .any(|element| compare_numbers(element, n) == Some(Ordering::Equal))
+    } else if let (Some(set), Some(b)) = (value.bs.as_ref(), operand.b.as_ref()) {
+        set.contains(b)
+    } else if let Some(list) = value.l.as_ref() {
+        list.iter().any(|element| equal_values(element, operand))
+    } else {
+        false
+    }
+}
+
+// `begins_with` is true for a string with the string prefix or a binary with the binary prefix.
+fn begins_with(value: &AttributeValue, prefix: &AttributeValue) -> bool {
+    if let (Some(value), Some(s)) = (value.s.as_ref(), prefix.s.as_ref()) {
+        value.starts_with(s.as_str())
+    } else if let (Some(value), Some(b)) = (value.b.as_ref(), prefix.b.as_ref()) {
+        value.starts_with(b)
+    } else {
+        false
+    }
+}
+
+// Returns the value which the path points to, if any.
+pub fn resolve_path<'a>(
+    item: &'a AttributeValues,
+    path: &DocumentPath,
+) -> Option<&'a AttributeValue> {
+    let mut value = item.get(&path.root)?;
+    for element in path.elements.iter() {
+        value = match element {
+            PathElement::Field(name) => value.m.as_ref()?.get(name)?,
+            PathElement::Index(index) => value.l.as_ref()?.get(*index)?,
+        };
+    }
+    Some(value)
+}
+
+fn resolve_operand<'a>(
+    item: &'a AttributeValues,
+    operand: &'a AttrOrPlaceholder,
+) -> Option<Cow<'a, AttributeValue>> {
+    match operand {
+        AttrOrPlaceholder::Attr(path) => resolve_path(item, path).map(Cow::Borrowed),
+        AttrOrPlaceholder::Placeholder(value) => Some(Cow::Borrowed(value)),
+        AttrOrPlaceholder::Size(path) => resolve_path(item, path).and_then(size).map(Cow::Owned),
+    }
+}
+
+fn attribute_type(value: &AttributeValue) -> Option<AttributeType> {
+    if value.s.is_some() {
+        Some(AttributeType::S)
+    } else if value.n.is_some() {
+        Some(AttributeType::N)
+    } else if value.b.is_some() {
+        Some(AttributeType::B)
+    } else if value.ss.is_some() {
+        Some(AttributeType::SS)
+    } else if value.ns.is_some() {
+        Some(AttributeType::NS)
+    } else if value.bs.is_some() {
+        Some(AttributeType::BS)
+    } else if value.bool.is_some() {
+        Some(AttributeType::BOOL)
+    } else if value.null.is_some() {
+        Some(AttributeType::NULL)
+    } else if value.l.is_some() {
+        Some(AttributeType::L)
+    } else if value.m.is_some() {
+        Some(AttributeType::M)
+    } else {
+        None
+    }
+}
+
+// `size` of a string or a binary is its length in bytes, and of a set, a list or a map is the number of its elements.
+fn size(value: &AttributeValue) -> Option<AttributeValue> {
+    let len = if let Some(s) = value.s.as_ref() {
+        s.len()
+    } else if let Some(b) = value.b.as_ref() {
+        b.len()
+    } else if let Some(ss) = value.ss.as_ref() {
+        ss.len()
+    } else if let Some(ns) = value.ns.as_ref() {
+        ns.len()
+    } else if let Some(bs) = value.bs.as_ref() {
+        bs.len()
+    } else if let Some(l) = value.l.as_ref() {
+        l.len()
+    } else if let Some(m) = value.m.as_ref() {
+        m.len()
+    } else {
+        return None;
+    };
+    Some(AttributeValue {
+        n: Some(len.to_string()),
+        ..AttributeValue::default()
+    })
+}
+
+// Only numbers, strings and binaries are ordered, and only against the same type.
+// Strings and binaries are compared byte by byte.
+fn compare_values(left: &AttributeValue, right: &AttributeValue) -> Option<Ordering> {
+    if let (Some(l), Some(r)) = (left.n.as_ref(), right.n.as_ref()) {
+        compare_numbers(l, r)
+    } else if let (Some(l), Some(r)) = (left.s.as_ref(), right.s.as_ref()) {
+        Some(l.as_bytes().cmp(r.as_bytes()))
+    } else if let (Some(l), Some(r)) = (left.b.as_ref(), right.b.as_ref()) {
+        Some(l.as_ref().cmp(r.as_ref()))
+    } else {
+        None
+    }
+}
+
+fn equal_values(left: &AttributeValue, right: &AttributeValue) -> bool {
+    fn same_set<T, F: Fn(&T, &T) -> bool>(left: &[T], right: &[T], eq: F) -> bool {
+        left.len() == right.len() && left.iter().all(|l| right.iter().any(|r| eq(l, r)))
+    }
+
+    if left.n.is_some() || left.s.is_some() || left.b.is_some() {
+        return compare_values(left, right) == Some(Ordering::Equal);
+    }
+    if let (Some(l), Some(r)) = (left.ss.as_ref(), right.ss.as_ref()) {
+        return same_set(l, r, |l, r| l == r);
+    }
+    if let (Some(l), Some(r)) = (left.ns.as_ref(), right.ns.as_ref()) {
+        return same_set(l, r, |l, r| compare_numbers(l, r) == Some(Ordering::Equal));
+    }
+    if let (Some(l), Some(r)) = (left.bs.as_ref(), right.bs.as_ref()) {
+        return same_set(l, r, |l, r| l == r);
+    }
+    if let (Some(l), Some(r)) = (left.bool, right.bool) {
+        return l == r;
+    }
+    if left.null.is_some() && right.null.is_some() {
+        return true;
+    }
+    if let (Some(l), Some(r)) = (left.l.as_ref(), right.l.as_ref()) {
+        return l.len() == r.len() && l.iter().zip(r.iter()).all(|(l, r)| equal_values(l, r));
+    }
+    if let (Some(l), Some(r)) = (left.m.as_ref(), right.m.as_ref()) {
+        return l.len() == r.len()
+            && l.iter()
+                .all(|(k, l)| matches!(r.get(k), Some(r) if equal_values(l, r)));
+    }
+    false
+}
+
+// A number as `0.digits * 10^exponent`, so that numbers are compared
+// with the precision of DynamoDB (up to 38 digits) rather than of `f64`.
+#[derive(Debug, PartialEq)]
+struct Decimal {
+    negative: bool,
+    digits: Vec<u8>,
+    exponent: i64,
+}
+
+fn parse_number(n: &str) -> Option<Decimal> {
+    let n = n.trim();
+    let (negative, n) = match n.as_bytes().first()? {
+        b'-' => (true, &n[1..]),
+        b'+' => (false, &n[1..]),
+        _ => (false, n),
+    };
+    let (mantissa, exponent) = match n.find(&['e', 'E'][..]) {
+        Some(i) => (&n[..i], n[i + 1..].parse::<i64>().ok()?),
+        None => (n, 0),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    // An exponent out of `i64`, e.g. `1e9223372036854775807`, is not a number here.
+    let mut exponent = exponent.checked_add(integer.len() as i64)?;
+    let mut digits = Vec::with_capacity(integer.len() + fraction.len());
+    for c in integer.chars().chain(fraction.chars()) {
+        digits.push(c.to_digit(10)? as u8);
+    }
+
+    let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..leading_zeros);
+    exponent = exponent.checked_sub(leading_zeros as i64)?;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        return Some(Decimal {
+            negative: false,
+            digits,
+            exponent: 0,
+        });
+    }
+    Some(Decimal {
+        negative,
+        digits,
+        exponent,
+    })
+}
+
+// Compares two numbers in the format of `AttributeValue.n`. Returns `None` if either is not a number.
+pub fn compare_numbers(left: &str, right: &str) -> Option<Ordering> {
+    fn sign(d: &Decimal) -> i8 {
+        match (d.digits.is_empty(), d.negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        }
+    }
+
+    let left = parse_number(left)?;
+    let right = parse_number(right)?;
+    let (left_sign, right_sign) = (sign(&left), sign(&right));
+    if left_sign != right_sign || left_sign == 0 {
+        return Some(left_sign.cmp(&right_sign));
+    }
+    let magnitude = left
+        .exponent
+        .cmp(&right.exponent)
+        .then_with(|| left.digits.cmp(&right.digits));
+    if left.negative {
+        Some(magnitude.reverse())
+    } else {
+        Some(magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_compare_numbers() {
+        assert_eq!(compare_numbers("1", "1.0"), Some(Ordering::Equal));
+        assert_eq!(compare_numbers("10", "9.99"), Some(Ordering::Greater));
+        assert_eq!(compare_numbers("-10", "-9.99"), Some(Ordering::Less));
+        assert_eq!(compare_numbers("0", "-0.0"), Some(Ordering::Equal));
+        assert_eq!(compare_numbers("-1", "0"), Some(Ordering::Less));
+        assert_eq!(compare_numbers("1.5E2", "150"), Some(Ordering::Equal));
+        assert_eq!(compare_numbers("0.001", "1e-2"), Some(Ordering::Less));
+        assert_eq!(
+            compare_numbers(
+                "12345678901234567890123456789012345678",
+                "12345678901234567890123456789012345679"
+            ),
+            Some(Ordering::Less)
+        );
+        assert_eq!(compare_numbers("1", "one"), None);
+        assert_eq!(compare_numbers("1e9223372036854775807", "1"), None);
+        assert_eq!(compare_numbers("0.01e-9223372036854775808", "1"), None);
+        assert_eq!(
+            compare_numbers("1e9223372036854775806", "1e9223372036854775805"),
+            Some(Ordering::Greater)
+        );
+    }
+}
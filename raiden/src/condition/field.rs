@@ -0,0 +1,130 @@
+// A condition on a single field, whose operands are bound to the type of the field `V`.
+// e.g. `User::cond().age().gt(30)`
+use super::{
+    AttrOrPlaceholder, BeginsWithOperand, Cond, ConditionComparisonExpression,
+    ConditionFilledOrWaitConjunction, ConditionFunctionExpression, ContainsOperand, DeleteOperand,
+    DocumentPath, IntoAttrName, IntoAttrPath, IntoAttribute,
+};
+
+pub struct FieldCondition<T, V> {
+    not: bool,
+    path: DocumentPath,
+    _token: std::marker::PhantomData<T>,
+    _value: std::marker::PhantomData<V>,
+}
+
+impl<T: Clone, V: IntoAttribute> FieldCondition<T, V> {
+    pub fn new<A: IntoAttrName>(attr: impl IntoAttrPath<A>) -> Self {
+        Self {
+            not: false,
+            path: attr.into_attr_path().into_document_path(),
+            _token: std::marker::PhantomData,
+            _value: std::marker::PhantomData,
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> Self {
+        self.not = true;
+        self
+    }
+
+    fn filled(self, cond: Cond) -> ConditionFilledOrWaitConjunction<T> {
+        ConditionFilledOrWaitConjunction {
+            not: self.not,
+            cond,
+            _token: std::marker::PhantomData,
+        }
+    }
+
+    fn compare(
+        self,
+        f: fn(AttrOrPlaceholder, AttrOrPlaceholder) -> ConditionComparisonExpression,
+        value: V,
+    ) -> ConditionFilledOrWaitConjunction<T> {
+        let attr = AttrOrPlaceholder::Attr(self.path.clone());
+        let value = AttrOrPlaceholder::Placeholder(value.into_attr());
+        self.filled(Cond::Cmp(f(attr, value)))
+    }
+
+    pub fn eq(self, value: impl Into<V>) -> ConditionFilledOrWaitConjunction<T> {
+        self.compare(ConditionComparisonExpression::Eq, value.into())
+    }
+
+    pub fn ne(self, value: impl Into<V>) -> ConditionFilledOrWaitConjunction<T> {
+        self.compare(ConditionComparisonExpression::Ne, value.into())
+    }
+
+    pub fn lt(self, value: impl Into<V>) -> ConditionFilledOrWaitConjunction<T> {
+        self.compare(ConditionComparisonExpression::Lt, value.into())
+    }
+
+    pub fn le(self, value: impl Into<V>) -> ConditionFilledOrWaitConjunction<T> {
+        self.compare(ConditionComparisonExpression::Le, value.into())
+    }
+
+    pub fn gt(self, value: impl Into<V>) -> ConditionFilledOrWaitConjunction<T> {
+        self.compare(ConditionComparisonExpression::Gt, value.into())
+    }
+
+    pub fn ge(self, value: impl Into<V>) -> ConditionFilledOrWaitConjunction<T> {
+        self.compare(ConditionComparisonExpression::Ge, value.into())
+    }
+
+    pub fn between(
+        self,
+        lower: impl Into<V>,
+        upper: impl Into<V>,
+    ) -> ConditionFilledOrWaitConjunction<T> {
+        let attr = AttrOrPlaceholder::Attr(self.path.clone());
+        let lower = AttrOrPlaceholder::Placeholder(lower.into().into_attr());
+        let upper = AttrOrPlaceholder::Placeholder(upper.into().into_attr());
+        self.filled(Cond::Cmp(ConditionComparisonExpression::Between(
+            attr, lower, upper,
+        )))
+    }
+
+    pub fn in_list<I: Into<V>>(self, values: Vec<I>) -> ConditionFilledOrWaitConjunction<T> {
+        let attr = AttrOrPlaceholder::Attr(self.path.clone());
+        let list = values
+            .into_iter()
+            .map(|value| AttrOrPlaceholder::Placeholder(value.into().into_attr()))
+            .collect();
+        self.filled(Cond::Cmp(ConditionComparisonExpression::In(attr, list)))
+    }
+
+    pub fn exists(self) -> ConditionFilledOrWaitConjunction<T> {
+        let func = ConditionFunctionExpression::AttributeExists(self.path.clone());
+        self.filled(Cond::Func(func))
+    }
+
+    pub fn not_exists(self) -> ConditionFilledOrWaitConjunction<T> {
+        let func = ConditionFunctionExpression::AttributeNotExists(self.path.clone());
+        self.filled(Cond::Func(func))
+    }
+}
+
+impl<T: Clone, V: IntoAttribute + BeginsWithOperand> FieldCondition<T, V> {
+    pub fn begins_with(self, prefix: impl Into<V::Prefix>) -> ConditionFilledOrWaitConjunction<T> {
+        let prefix = V::prefix_into_attr(prefix.into());
+        let func = ConditionFunctionExpression::BeginsWith(self.path.clone(), prefix);
+        self.filled(Cond::Func(func))
+    }
+}
+
+impl<T: Clone, V: IntoAttribute + ContainsOperand> FieldCondition<T, V> {
+    // For a string, `operand` is a substring. For a set or a list, it is an element.
+    pub fn contains(self, operand: impl Into<V::Operand>) -> ConditionFilledOrWaitConjunction<T> {
+        let operand = V::operand_into_attr(operand.into());
+        let func = ConditionFunctionExpression::Contains(self.path.clone(), operand);
+        self.filled(Cond::Func(func))
+    }
+}
+
+// Sets are the field types which accept `DELETE`.
+impl<T: Clone, V: IntoAttribute + ContainsOperand + DeleteOperand> FieldCondition<T, V> {
+    // Whether the set has the element, e.g. `User::cond().scores().in_set(3)`.
+    pub fn in_set(self, element: impl Into<V::Operand>) -> ConditionFilledOrWaitConjunction<T> {
+        self.contains(element)
+    }
+}
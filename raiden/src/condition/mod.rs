@@ -1,26 +1,75 @@
 // https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.OperatorsAndFunctions.html
-use super::*;
+pub mod eval;
+pub mod field;
+
+pub use eval::*;
+pub use field::*;
 
-pub type AttrName = String;
+use super::*;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum ConditionFunctionExpression {
-    AttributeExists(AttrName),
-    AttributeNotExists(AttrName),
-    AttributeType(AttrName, super::AttributeType),
-    BeginsWith(AttrName, String),
-    Contains(AttrName, String),
-    Size(AttrName),
+    AttributeExists(DocumentPath),
+    AttributeNotExists(DocumentPath),
+    AttributeType(DocumentPath, super::AttributeType),
+    // The prefix is a string or a binary.
+    BeginsWith(DocumentPath, super::AttributeValue),
+    // The operand is a substring of a string, or an element of a set or a list.
+    Contains(DocumentPath, super::AttributeValue),
+    Size(DocumentPath),
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum ConditionComparisonExpression {
-    Eq(
-        AttrOrPlaceholder,
-        Option<super::AttributeValue>,
-        AttrOrPlaceholder,
-        Option<super::AttributeValue>,
-    ),
+    Eq(AttrOrPlaceholder, AttrOrPlaceholder),
+    Ne(AttrOrPlaceholder, AttrOrPlaceholder),
+    Lt(AttrOrPlaceholder, AttrOrPlaceholder),
+    Le(AttrOrPlaceholder, AttrOrPlaceholder),
+    Gt(AttrOrPlaceholder, AttrOrPlaceholder),
+    Ge(AttrOrPlaceholder, AttrOrPlaceholder),
+    Between(AttrOrPlaceholder, AttrOrPlaceholder, AttrOrPlaceholder),
+    In(AttrOrPlaceholder, Vec<AttrOrPlaceholder>),
+}
+
+impl ConditionComparisonExpression {
+    // Returns every operand of the expression, left hand side first.
+    fn operands(&self) -> Vec<&AttrOrPlaceholder> {
+        match self {
+            Self::Eq(left, right)
+            | Self::Ne(left, right)
+            | Self::Lt(left, right)
+            | Self::Le(left, right)
+            | Self::Gt(left, right)
+            | Self::Ge(left, right) => vec![left, right],
+            Self::Between(left, lower, upper) => vec![left, lower, upper],
+            Self::In(left, list) => std::iter::once(left).chain(list.iter()).collect(),
+        }
+    }
+
+    fn build(self, ctx: &mut super::ValueIdContext, values: &mut super::AttributeValues) -> String {
+        let mut operand = |o: AttrOrPlaceholder| o.build(ctx, values);
+        match self {
+            Self::Eq(left, right) => format!("{} = {}", operand(left), operand(right)),
+            Self::Ne(left, right) => format!("{} <> {}", operand(left), operand(right)),
+            Self::Lt(left, right) => format!("{} < {}", operand(left), operand(right)),
+            Self::Le(left, right) => format!("{} <= {}", operand(left), operand(right)),
+            Self::Gt(left, right) => format!("{} > {}", operand(left), operand(right)),
+            Self::Ge(left, right) => format!("{} >= {}", operand(left), operand(right)),
+            Self::Between(left, lower, upper) => format!(
+                "{} BETWEEN {} AND {}",
+                operand(left),
+                operand(lower),
+                operand(upper)
+            ),
+            Self::In(left, list) => {
+                let left = operand(left);
+                let list = list.into_iter().map(operand).collect::<Vec<String>>();
+                format!("{} IN ({})", left, list.join(", "))
+            }
+        }
+    }
 }
 
 #[derive(Clone, PartialEq)]
@@ -32,116 +81,163 @@ pub struct ConditionFilledOrWaitConjunction<T: Clone> {
 
 #[derive(Clone, PartialEq)]
 pub struct ConditionFilled<T: Clone> {
-    pub not: bool,
     pub cond: Cond,
-    pub conjunction: Conjunction,
     pub _token: std::marker::PhantomData<T>,
 }
 
 impl<T: Clone> ConditionFilledOrWaitConjunction<T> {
     pub fn and(self, cond: impl ConditionBuilder<T>) -> ConditionFilled<T> {
-        let (condition_string, attr_names, attr_values) = cond.build();
         ConditionFilled {
-            not: self.not,
-            cond: self.cond,
-            conjunction: Conjunction::And(condition_string, attr_names, attr_values),
+            cond: Cond::And(Box::new(self.into_cond()), Box::new(cond.into_cond())),
+            _token: std::marker::PhantomData,
+        }
+    }
+
+    pub fn or(self, cond: impl ConditionBuilder<T>) -> ConditionFilled<T> {
+        ConditionFilled {
+            cond: Cond::Or(Box::new(self.into_cond()), Box::new(cond.into_cond())),
+            _token: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> ConditionFilled<T> {
+    // Wraps a `Cond` which is not built from a typed builder, e.g. parsed by `expression::parse_condition`.
+    // Its attributes are not checked against the struct of `T`, so this has to be chosen explicitly.
+    pub fn from_parsed(cond: Cond) -> Self {
+        ConditionFilled {
+            cond,
+            _token: std::marker::PhantomData,
+        }
+    }
+
+    pub fn and(self, cond: impl ConditionBuilder<T>) -> ConditionFilled<T> {
+        ConditionFilled {
+            cond: Cond::And(Box::new(self.cond), Box::new(cond.into_cond())),
+            _token: self._token,
+        }
+    }
+
+    pub fn or(self, cond: impl ConditionBuilder<T>) -> ConditionFilled<T> {
+        ConditionFilled {
+            cond: Cond::Or(Box::new(self.cond), Box::new(cond.into_cond())),
             _token: self._token,
         }
     }
 }
 
 impl<T: Clone> ConditionBuilder<T> for ConditionFilledOrWaitConjunction<T> {
-    fn build(self) -> (String, super::AttributeNames, super::AttributeValues) {
+    fn into_cond(self) -> Cond {
         if self.not {
-            (
-                format!("NOT ({})", self.cond.to_string()),
-                self.cond.to_attr_names(),
-                self.cond.into_attr_values(),
-            )
+            Cond::Not(Box::new(self.cond))
         } else {
-            (
-                self.cond.to_string(),
-                self.cond.to_attr_names(),
-                self.cond.into_attr_values(),
-            )
+            self.cond
         }
     }
 }
+
 impl<T: Clone> ConditionBuilder<T> for ConditionFilled<T> {
-    fn build(self) -> (String, super::AttributeNames, super::AttributeValues) {
-        let (right_str, right_names, right_values) = match self.conjunction {
-            super::condition::Conjunction::And(s, m, v) => (format!("AND ({})", s), m, v),
-            super::condition::Conjunction::Or(s, m, v) => (format!("OR ({})", s), m, v),
-        };
-        let left_str = self.cond.to_string();
-        let left_names = self.cond.to_attr_names();
-        let left_values = self.cond.into_attr_values();
-        if self.not {
-            (
-                format!("NOT ({}) {}", left_str, right_str),
-                super::merge_map(left_names, right_names),
-                super::merge_map(left_values, right_values),
-            )
-        } else {
-            (
-                format!("{} {}", left_str, right_str),
-                super::merge_map(left_names, right_names),
-                super::merge_map(left_values, right_values),
-            )
-        }
+    fn into_cond(self) -> Cond {
+        self.cond
     }
 }
 
 impl std::string::ToString for ConditionFunctionExpression {
     fn to_string(&self) -> String {
-        use crypto::digest::Digest;
-        use crypto::md5::Md5;
         match self {
-            Self::AttributeExists(path) => format!("attribute_exists(#{})", path),
-            Self::AttributeNotExists(path) => format!("attribute_not_exists(#{})", path),
+            Self::AttributeExists(path) => format!("attribute_exists({})", path),
+            Self::AttributeNotExists(path) => {
+                format!("attribute_not_exists({})", path)
+            }
             Self::AttributeType(path, attribute_type) => format!(
-                "attribute_type(#{}, :type{})",
+                "attribute_type({}, :type{})",
                 path,
                 attribute_type.to_string()
             ),
-            Self::BeginsWith(path, s) => {
-                let mut md5 = Md5::new();
-                md5.input(s.as_bytes());
-                format!("begins_with(#{}, :begins_with_{})", path, md5.result_str())
+            Self::BeginsWith(path, prefix) => {
+                format!("begins_with({}, {})", path, begins_with_placeholder(prefix))
             }
-            Self::Contains(path, s) => {
-                let mut md5 = Md5::new();
-                md5.input(s.as_bytes());
-                format!("contains(#{}, :contains_{})", path, md5.result_str())
-            }
-            Self::Size(_path) => {
-                unimplemented!("Size condition expression is not implemented yet.")
+            Self::Contains(path, value) => {
+                format!("contains({}, {})", path, contains_placeholder(value))
             }
+            Self::Size(path) => format!("size({})", path),
         }
     }
 }
 
+// A string prefix is hashed by itself, and a binary one with its type.
+fn begins_with_placeholder(prefix: &super::AttributeValue) -> super::Placeholder {
+    use crypto::digest::Digest;
+    use crypto::md5::Md5;
+    let mut md5 = Md5::new();
+    match prefix.s.as_ref() {
+        Some(s) => md5.input(s.as_bytes()),
+        None => md5.input(canonical_value(prefix).as_bytes()),
+    }
+    format!(":begins_with_{}", md5.result_str())
+}
+
+// The placeholder is derived from the value, so that the same operand always gets the same placeholder
+// and operands of different types never share one.
+fn contains_placeholder(value: &super::AttributeValue) -> super::Placeholder {
+    use crypto::digest::Digest;
+    use crypto::md5::Md5;
+    let mut md5 = Md5::new();
+    md5.input(canonical_value(value).as_bytes());
+    format!(":contains_{}", md5.result_str())
+}
+
+// Renders the value with its type, with set elements and map keys sorted.
+fn canonical_value(value: &super::AttributeValue) -> String {
+    fn sorted<T: Ord + std::fmt::Debug>(mut items: Vec<T>) -> String {
+        items.sort();
+        format!("{:?}", items)
+    }
+
+    if let Some(s) = value.s.as_ref() {
+        format!("S{:?}", s)
+    } else if let Some(n) = value.n.as_ref() {
+        format!("N{:?}", n)
+    } else if let Some(b) = value.b.as_ref() {
+        format!("B{:?}", b.as_ref())
+    } else if let Some(ss) = value.ss.as_ref() {
+        format!("SS{}", sorted(ss.iter().collect()))
+    } else if let Some(ns) = value.ns.as_ref() {
+        format!("NS{}", sorted(ns.iter().collect()))
+    } else if let Some(bs) = value.bs.as_ref() {
+        format!("BS{}", sorted(bs.iter().map(|b| b.as_ref()).collect()))
+    } else if let Some(b) = value.bool {
+        format!("BOOL{}", b)
+    } else if let Some(l) = value.l.as_ref() {
+        let items: Vec<String> = l.iter().map(canonical_value).collect();
+        format!("L[{}]", items.join(","))
+    } else if let Some(m) = value.m.as_ref() {
+        let mut items: Vec<String> = m
+            .iter()
+            .map(|(k, v)| format!("{:?}:{}", k, canonical_value(v)))
+            .collect();
+        items.sort();
+        format!("M{{{}}}", items.join(","))
+    } else {
+        "NULL".to_owned()
+    }
+}
+
 impl super::ToAttrNames for ConditionFunctionExpression {
     fn to_attr_names(&self) -> super::AttributeNames {
-        let mut m: super::AttributeNames = std::collections::HashMap::new();
         match self {
             Self::Contains(path, _)
             | Self::BeginsWith(path, _)
             | Self::AttributeType(path, _)
             | Self::AttributeExists(path)
-            | Self::AttributeNotExists(path) => {
-                m.insert(format!("#{}", path), path.clone());
-            }
-            _ => {}
+            | Self::AttributeNotExists(path)
+            | Self::Size(path) => path.to_attr_names(),
         }
-        m
     }
 }
 
 impl super::IntoAttrValues for ConditionFunctionExpression {
     fn into_attr_values(self) -> super::AttributeValues {
-        use crypto::digest::Digest;
-        use crypto::md5::Md5;
         let mut m: super::AttributeValues = std::collections::HashMap::new();
         match self {
             Self::AttributeType(_path, t) => {
@@ -153,27 +249,11 @@ impl super::IntoAttrValues for ConditionFunctionExpression {
                     },
                 );
             }
-            Self::BeginsWith(_path, s) => {
-                let mut md5 = Md5::new();
-                md5.input(s.as_bytes());
-                m.insert(
-                    format!(":begins_with_{}", md5.result_str()),
-                    super::AttributeValue {
-                        s: Some(s),
-                        ..super::AttributeValue::default()
-                    },
-                );
+            Self::BeginsWith(_path, prefix) => {
+                m.insert(begins_with_placeholder(&prefix), prefix);
             }
-            Self::Contains(_path, s) => {
-                let mut md5 = Md5::new();
-                md5.input(s.as_bytes());
-                m.insert(
-                    format!(":contains_{}", md5.result_str()),
-                    super::AttributeValue {
-                        s: Some(s),
-                        ..super::AttributeValue::default()
-                    },
-                );
+            Self::Contains(_path, value) => {
+                m.insert(contains_placeholder(&value), value);
             }
             _ => {}
         }
@@ -181,92 +261,84 @@ impl super::IntoAttrValues for ConditionFunctionExpression {
     }
 }
 
-impl std::string::ToString for ConditionComparisonExpression {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Eq(left, _, right, _) => format!("{} = {}", left.to_string(), right.to_string()),
-        }
-    }
-}
-
 impl super::ToAttrNames for ConditionComparisonExpression {
     fn to_attr_names(&self) -> super::AttributeNames {
         let mut m: super::AttributeNames = std::collections::HashMap::new();
-        match self {
-            Self::Eq(left, _, right, _) => {
-                if let AttrOrPlaceholder::Attr(l) = left {
-                    m.insert(left.to_string(), l.clone());
-                }
-                if let AttrOrPlaceholder::Attr(r) = right {
-                    m.insert(right.to_string(), r.clone());
-                }
-            }
-        }
-        m
-    }
-}
-
-impl super::IntoAttrValues for ConditionComparisonExpression {
-    fn into_attr_values(self) -> super::AttributeValues {
-        let mut m: super::AttributeValues = std::collections::HashMap::new();
-
-        match self {
-            Self::Eq(left, left_value, right, right_value) => {
-                if let Some(left_value) = left_value {
-                    m.insert(left.to_string(), left_value);
-                }
-                if let Some(right_value) = right_value {
-                    m.insert(right.to_string(), right_value);
+        for operand in self.operands() {
+            match operand {
+                AttrOrPlaceholder::Attr(path) | AttrOrPlaceholder::Size(path) => {
+                    m = super::merge_map(m, path.to_attr_names());
                 }
+                AttrOrPlaceholder::Placeholder(_) => {}
             }
         }
         m
     }
 }
 
+// A value is held as is, and gets its placeholder when the expression is built.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum AttrOrPlaceholder {
-    Attr(String),
-    Placeholder(String),
+    Attr(DocumentPath),
+    Placeholder(super::AttributeValue),
+    Size(DocumentPath),
 }
 
-impl std::string::ToString for AttrOrPlaceholder {
-    fn to_string(&self) -> String {
+impl AttrOrPlaceholder {
+    fn build(self, ctx: &mut super::ValueIdContext, values: &mut super::AttributeValues) -> String {
         match self {
-            Self::Placeholder(p) => format!(":{}", p),
-            Self::Attr(a) => format!("#{}", a),
+            Self::Placeholder(value) => {
+                let placeholder = ctx.next_placeholder();
+                values.insert(placeholder.clone(), value);
+                placeholder
+            }
+            Self::Attr(path) => path.to_string(),
+            Self::Size(path) => format!("size({})", path),
         }
     }
 }
 
 pub type ConditionString = String;
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum Conjunction {
-    And(
-        ConditionString,
-        super::AttributeNames,
-        super::AttributeValues,
-    ),
-    Or(
-        ConditionString,
-        super::AttributeNames,
-        super::AttributeValues,
-    ),
-}
-
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Cond {
     Func(ConditionFunctionExpression),
     Cmp(ConditionComparisonExpression),
+    Not(Box<Cond>),
+    And(Box<Cond>, Box<Cond>),
+    Or(Box<Cond>, Box<Cond>),
 }
 
-impl std::string::ToString for Cond {
-    fn to_string(&self) -> String {
+impl Cond {
+    // Renders the condition, registering its values with placeholders issued by `ctx`.
+    fn build(self, ctx: &mut super::ValueIdContext, values: &mut super::AttributeValues) -> String {
         match self {
-            Self::Func(func) => func.to_string(),
-            Self::Cmp(cmp) => cmp.to_string(),
+            Self::Func(func) => {
+                let expression = func.to_string();
+                *values = super::merge_map(std::mem::take(values), func.into_attr_values());
+                expression
+            }
+            Self::Cmp(cmp) => cmp.build(ctx, values),
+            Self::Not(cond) => format!("NOT ({})", cond.build(ctx, values)),
+            // The right hand side is always grouped. `AND` binds tighter than `OR`,
+            // so only an `OR` on the left hand side of `AND` needs parentheses.
+            Self::And(left, right) => {
+                let grouped = matches!(*left, Self::Or(_, _));
+                let left = left.build(ctx, values);
+                let right = right.build(ctx, values);
+                if grouped {
+                    format!("({}) AND ({})", left, right)
+                } else {
+                    format!("{} AND ({})", left, right)
+                }
+            }
+            Self::Or(left, right) => {
+                let left = left.build(ctx, values);
+                let right = right.build(ctx, values);
+                format!("{} OR ({})", left, right)
+            }
         }
     }
 }
@@ -276,25 +348,54 @@ impl super::ToAttrNames for Cond {
         match self {
             Self::Func(cond) => cond.to_attr_names(),
             Self::Cmp(cmp) => cmp.to_attr_names(),
-        }
-    }
-}
-
-impl super::IntoAttrValues for Cond {
-    fn into_attr_values(self) -> super::AttributeValues {
-        match self {
-            Self::Func(cond) => cond.into_attr_values(),
-            Self::Cmp(cmp) => cmp.into_attr_values(),
+            Self::Not(cond) => cond.to_attr_names(),
+            Self::And(left, right) | Self::Or(left, right) => {
+                super::merge_map(left.to_attr_names(), right.to_attr_names())
+            }
         }
     }
 }
 
 pub trait ConditionBuilder<T> {
+    fn into_cond(self) -> Cond;
+
     fn build(
         self,
     ) -> (
         ConditionString,
         super::AttributeNames,
         super::AttributeValues,
-    );
+    )
+    where
+        Self: Sized,
+    {
+        self.build_with(&mut super::ValueIdContext::new())
+    }
+
+    // Builds the condition with placeholders issued by `ctx`,
+    // so that it can be merged with other expressions of the same request.
+    fn build_with(
+        self,
+        ctx: &mut super::ValueIdContext,
+    ) -> (
+        ConditionString,
+        super::AttributeNames,
+        super::AttributeValues,
+    )
+    where
+        Self: Sized,
+    {
+        let cond = self.into_cond();
+        let names = cond.to_attr_names();
+        let mut values: super::AttributeValues = std::collections::HashMap::new();
+        (cond.build(ctx, &mut values), names, values)
+    }
+
+    // Evaluates the condition against an item without calling DynamoDB. See `Cond::evaluate`.
+    fn evaluate(&self, item: &super::AttributeValues) -> bool
+    where
+        Self: Clone + Sized,
+    {
+        self.clone().into_cond().evaluate(item)
+    }
 }
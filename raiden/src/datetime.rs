@@ -0,0 +1,453 @@
+// Time values as attributes.
+// They are stored as sortable ISO-8601 strings in UTC by default, e.g. `2021-01-02T03:04:05.000000000Z`,
+// so that they work as sort keys. Wrap them with `EpochSeconds` or `EpochMillis` to store numbers,
+// e.g. for TTL attributes, or use `#[raiden(time_format = "epoch_seconds")]` on a field.
+// ISO-8601 strings only cover the years 0000 to 9999. Times out of them are written with
+// 5-digit or negative years, which can not be read back and do not sort, so use epoch formats for them.
+use super::{AttributeValue, ConversionError, FromAttribute, IntoAttribute, IntoSortKeyRange};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_MILLI: i128 = 1_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+pub trait TimeValue: Sized {
+    fn to_unix_nanos(&self) -> i128;
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self, ConversionError>;
+
+    fn to_iso8601(&self) -> String {
+        format_iso8601(self.to_unix_nanos())
+    }
+
+    fn from_iso8601(s: &str) -> Result<Self, ConversionError> {
+        Self::from_unix_nanos(parse_iso8601(s)?)
+    }
+}
+
+// See. http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((m + 9) % 12) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+fn format_date(days: i64) -> String {
+    let (y, m, d) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+// The fraction always has nine digits, so that the strings sort in time order.
+// Only the years 0000 to 9999 can be parsed back by `parse_iso8601`.
+pub fn format_iso8601(nanos: i128) -> String {
+    let secs = nanos.div_euclid(NANOS_PER_SECOND) as i64;
+    let subsec = nanos.rem_euclid(NANOS_PER_SECOND);
+    let (days, secs) = (
+        secs.div_euclid(SECONDS_PER_DAY),
+        secs.rem_euclid(SECONDS_PER_DAY),
+    );
+    format!(
+        "{}T{:02}:{:02}:{:02}.{:09}Z",
+        format_date(days),
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        subsec
+    )
+}
+
+// Parses `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)` into nanoseconds since the epoch.
+pub fn parse_iso8601(s: &str) -> Result<i128, ConversionError> {
+    let err = || ConversionError::ParseTime(s.to_owned());
+    let b = s.as_bytes();
+    let num = |from: usize, len: usize| -> Result<i64, ConversionError> {
+        let digits = b.get(from..from + len).ok_or_else(err)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return Err(err());
+        }
+        Ok(digits.iter().fold(0, |n, d| n * 10 + i64::from(d - b'0')))
+    };
+    let expect = |at: usize, chars: &[u8]| match b.get(at) {
+        Some(c) if chars.contains(c) => Ok(()),
+        _ => Err(err()),
+    };
+
+    let (y, m, d) = (num(0, 4)?, num(5, 2)?, num(8, 2)?);
+    expect(4, b"-")?;
+    expect(7, b"-")?;
+    let days = days_from_civil(y, m, d);
+    if !(1..=12).contains(&m) || civil_from_days(days) != (y, m, d) {
+        return Err(err());
+    }
+    if b.len() == 10 {
+        return Ok(i128::from(days * SECONDS_PER_DAY) * NANOS_PER_SECOND);
+    }
+
+    expect(10, b"Tt ")?;
+    expect(13, b":")?;
+    expect(16, b":")?;
+    let (h, min, sec) = (num(11, 2)?, num(14, 2)?, num(17, 2)?);
+    if h > 23 || min > 59 || sec > 59 {
+        return Err(err());
+    }
+
+    let mut at = 19;
+    let mut subsec = 0;
+    if b.get(at) == Some(&b'.') {
+        let start = at + 1;
+        at = start;
+        while matches!(b.get(at), Some(c) if c.is_ascii_digit()) {
+            at += 1;
+        }
+        if at == start {
+            return Err(err());
+        }
+        // Digits finer than nanoseconds are truncated.
+        subsec = (start..start + 9).fold(0, |n, i| {
+            let d = b.get(i).filter(|_| i < at).map_or(0, |d| d - b'0');
+            n * 10 + i128::from(d)
+        });
+    }
+
+    let offset = match b.get(at) {
+        Some(b'Z') | Some(b'z') if at + 1 == b.len() => 0,
+        Some(&sign) if (sign == b'+' || sign == b'-') && at + 6 == b.len() => {
+            expect(at + 3, b":")?;
+            let offset = num(at + 1, 2)? * 3600 + num(at + 4, 2)? * 60;
+            if sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return Err(err()),
+    };
+
+    let secs = days * SECONDS_PER_DAY + h * 3600 + min * 60 + sec - offset;
+    Ok(i128::from(secs) * NANOS_PER_SECOND + subsec)
+}
+
+fn out_of_range(nanos: i128) -> ConversionError {
+    ConversionError::ParseTime(nanos.to_string())
+}
+
+impl TimeValue for std::time::SystemTime {
+    fn to_unix_nanos(&self) -> i128 {
+        match self.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => d.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        }
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self, ConversionError> {
+        let duration = |n: i128| {
+            let secs = std::convert::TryFrom::try_from(n / NANOS_PER_SECOND).ok()?;
+            Some(std::time::Duration::new(
+                secs,
+                (n % NANOS_PER_SECOND) as u32,
+            ))
+        };
+        let time = if nanos >= 0 {
+            duration(nanos).and_then(|d| std::time::UNIX_EPOCH.checked_add(d))
+        } else {
+            duration(-nanos).and_then(|d| std::time::UNIX_EPOCH.checked_sub(d))
+        };
+        time.ok_or_else(|| out_of_range(nanos))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TimeValue for ::chrono::DateTime<::chrono::Utc> {
+    fn to_unix_nanos(&self) -> i128 {
+        i128::from(self.timestamp()) * NANOS_PER_SECOND + i128::from(self.timestamp_subsec_nanos())
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self, ConversionError> {
+        use ::chrono::TimeZone;
+        let secs = std::convert::TryFrom::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+            .map_err(|_| out_of_range(nanos))?;
+        let subsec = nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+        ::chrono::Utc
+            .timestamp_opt(secs, subsec)
+            .single()
+            .ok_or_else(|| out_of_range(nanos))
+    }
+}
+
+// A date is the midnight of the day in UTC, and is stored as `YYYY-MM-DD`.
+#[cfg(feature = "chrono")]
+impl TimeValue for ::chrono::NaiveDate {
+    fn to_unix_nanos(&self) -> i128 {
+        use ::chrono::Datelike;
+        let days = i64::from(self.num_days_from_ce()) - i64::from(UNIX_EPOCH_DAYS_FROM_CE);
+        i128::from(days * SECONDS_PER_DAY) * NANOS_PER_SECOND
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self, ConversionError> {
+        let days = nanos.div_euclid(i128::from(SECONDS_PER_DAY) * NANOS_PER_SECOND);
+        std::convert::TryFrom::try_from(days + i128::from(UNIX_EPOCH_DAYS_FROM_CE))
+            .ok()
+            .and_then(::chrono::NaiveDate::from_num_days_from_ce_opt)
+            .ok_or_else(|| out_of_range(nanos))
+    }
+
+    fn to_iso8601(&self) -> String {
+        let days = self.to_unix_nanos() / NANOS_PER_SECOND / i128::from(SECONDS_PER_DAY);
+        format_date(days as i64)
+    }
+}
+
+#[cfg(feature = "chrono")]
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+#[cfg(feature = "time")]
+impl TimeValue for ::time::OffsetDateTime {
+    fn to_unix_nanos(&self) -> i128 {
+        self.unix_timestamp_nanos()
+    }
+
+    // Only the years which ISO-8601 strings can represent, i.e. 0000 to 9999, are supported.
+    fn from_unix_nanos(nanos: i128) -> Result<Self, ConversionError> {
+        let min = i128::from(days_from_civil(0, 1, 1) * SECONDS_PER_DAY) * NANOS_PER_SECOND;
+        let max = i128::from(days_from_civil(10000, 1, 1) * SECONDS_PER_DAY) * NANOS_PER_SECOND;
+        if nanos < min || nanos >= max {
+            return Err(out_of_range(nanos));
+        }
+        Ok(::time::OffsetDateTime::from_unix_timestamp_nanos(nanos))
+    }
+}
+
+macro_rules! default_time_convertor {
+    ($to: ty) => {
+        impl IntoAttribute for $to {
+            fn into_attr(self) -> AttributeValue {
+                AttributeValue {
+                    s: Some(self.to_iso8601()),
+                    ..AttributeValue::default()
+                }
+            }
+        }
+
+        impl FromAttribute for $to {
+            fn from_attr(value: Option<AttributeValue>) -> Result<Self, ConversionError> {
+                let s = value
+                    .and_then(|value| value.s)
+                    .ok_or(ConversionError::ValueIsNone)?;
+                <$to>::from_iso8601(&s)
+            }
+        }
+    };
+}
+
+default_time_convertor!(std::time::SystemTime);
+#[cfg(feature = "chrono")]
+default_time_convertor!(::chrono::DateTime<::chrono::Utc>);
+#[cfg(feature = "chrono")]
+default_time_convertor!(::chrono::NaiveDate);
+#[cfg(feature = "time")]
+default_time_convertor!(::time::OffsetDateTime);
+
+macro_rules! epoch_convertor {
+    ($name: ident, $unit: expr) => {
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<T>(pub T);
+
+        impl<T> From<T> for $name<T> {
+            fn from(v: T) -> Self {
+                Self(v)
+            }
+        }
+
+        impl<T: TimeValue> IntoAttribute for $name<T> {
+            fn into_attr(self) -> AttributeValue {
+                AttributeValue {
+                    n: Some(self.0.to_unix_nanos().div_euclid($unit).to_string()),
+                    ..AttributeValue::default()
+                }
+            }
+        }
+
+        impl<T: TimeValue> FromAttribute for $name<T> {
+            fn from_attr(value: Option<AttributeValue>) -> Result<Self, ConversionError> {
+                let n = value
+                    .and_then(|value| value.n)
+                    .ok_or(ConversionError::ValueIsNone)?;
+                let n: i128 = n.parse().map_err(|_| ConversionError::ParseTime(n))?;
+                let nanos = n
+                    .checked_mul($unit)
+                    .ok_or_else(|| ConversionError::ParseTime(n.to_string()))?;
+                T::from_unix_nanos(nanos).map($name)
+            }
+        }
+
+        // For `Option<T>` fields with `time_format`.
+        impl<T: TimeValue> IntoAttribute for $name<Option<T>> {
+            fn into_attr(self) -> AttributeValue {
+                self.0.map($name).into_attr()
+            }
+        }
+
+        impl<T: TimeValue> FromAttribute for $name<Option<T>> {
+            fn from_attr(value: Option<AttributeValue>) -> Result<Self, ConversionError> {
+                let value: Option<$name<T>> = FromAttribute::from_attr(value)?;
+                Ok($name(value.map(|v| v.0)))
+            }
+        }
+    };
+}
+
+epoch_convertor!(EpochSeconds, NANOS_PER_SECOND);
+epoch_convertor!(EpochMillis, NANOS_PER_MILLI);
+
+// Ranges of time values for a sort key with `time_format`, e.g. `.sort_key(from..=to)`.
+macro_rules! epoch_sort_key_range {
+    ($name: ident) => {
+        impl<T: TimeValue + PartialOrd> IntoSortKeyRange<$name<T>> for std::ops::RangeInclusive<T> {
+            fn into_sort_key_range(self) -> Option<super::KeyConditionTypes> {
+                let (start, end) = self.into_inner();
+                ($name(start)..=$name(end)).into_sort_key_range()
+            }
+        }
+
+        impl<T: TimeValue> IntoSortKeyRange<$name<T>> for std::ops::RangeFrom<T> {
+            fn into_sort_key_range(self) -> Option<super::KeyConditionTypes> {
+                ($name(self.start)..).into_sort_key_range()
+            }
+        }
+
+        impl<T: TimeValue> IntoSortKeyRange<$name<T>> for std::ops::RangeTo<T> {
+            fn into_sort_key_range(self) -> Option<super::KeyConditionTypes> {
+                (..$name(self.end)).into_sort_key_range()
+            }
+        }
+
+        impl<T: TimeValue> IntoSortKeyRange<$name<T>> for std::ops::RangeToInclusive<T> {
+            fn into_sort_key_range(self) -> Option<super::KeyConditionTypes> {
+                (..=$name(self.end)).into_sort_key_range()
+            }
+        }
+    };
+}
+
+epoch_sort_key_range!(EpochSeconds);
+epoch_sort_key_range!(EpochMillis);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_and_parse_iso8601() {
+        assert_eq!(format_iso8601(0), "1970-01-01T00:00:00.000000000Z");
+        assert_eq!(
+            format_iso8601(951_782_400_123_456_789),
+            "2000-02-29T00:00:00.123456789Z"
+        );
+        assert_eq!(format_iso8601(-1), "1969-12-31T23:59:59.999999999Z");
+        assert_eq!(
+            parse_iso8601("2000-02-29T00:00:00.123456789Z").unwrap(),
+            951_782_400_123_456_789
+        );
+        assert_eq!(
+            parse_iso8601("2000-02-29T09:00:00.5+09:00").unwrap(),
+            951_782_400_500_000_000
+        );
+        assert_eq!(
+            parse_iso8601("2000-02-29").unwrap(),
+            951_782_400_000_000_000
+        );
+        assert!(parse_iso8601("2001-02-29").is_err());
+        assert!(parse_iso8601("2000-02-29T24:00:00Z").is_err());
+        assert!(parse_iso8601("2000-02-29T00:00:00").is_err());
+        assert!(parse_iso8601("2000-02-29T00:00:00.Z").is_err());
+
+        // The first and the last moments which round-trip.
+        let min = parse_iso8601("0000-01-01T00:00:00Z").unwrap();
+        assert_eq!(format_iso8601(min), "0000-01-01T00:00:00.000000000Z");
+        let max = parse_iso8601("9999-12-31T23:59:59.999999999Z").unwrap();
+        assert_eq!(format_iso8601(max), "9999-12-31T23:59:59.999999999Z");
+        assert_eq!(format_iso8601(max + 1), "10000-01-01T00:00:00.000000000Z");
+        assert!(parse_iso8601(&format_iso8601(max + 1)).is_err());
+        assert!(parse_iso8601(&format_iso8601(min - 1)).is_err());
+    }
+
+    #[test]
+    fn test_time_into_attr_and_back() {
+        let at = std::time::UNIX_EPOCH + std::time::Duration::from_millis(1_600_000_000_250);
+        let value = at.into_attr();
+        assert_eq!(value.s, Some("2020-09-13T12:26:40.250000000Z".to_owned()));
+        assert_eq!(std::time::SystemTime::from_attr(Some(value)).unwrap(), at);
+
+        let value = EpochSeconds(at).into_attr();
+        assert_eq!(value.n, Some("1600000000".to_owned()));
+        let value = EpochMillis(at).into_attr();
+        assert_eq!(value.n, Some("1600000000250".to_owned()));
+        assert_eq!(
+            EpochMillis::<std::time::SystemTime>::from_attr(Some(value)).unwrap(),
+            EpochMillis(at)
+        );
+        assert_eq!(
+            EpochSeconds(None::<std::time::SystemTime>).into_attr().null,
+            Some(true)
+        );
+
+        assert!(matches!(
+            std::time::SystemTime::from_attr(Some("yesterday".into_attr())),
+            Err(ConversionError::ParseTime(_))
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_into_attr_and_back() {
+        use ::chrono::TimeZone;
+        let at = ::chrono::Utc.timestamp_opt(1_600_000_000, 250).unwrap();
+        let value = at.into_attr();
+        assert_eq!(value.s, Some("2020-09-13T12:26:40.000000250Z".to_owned()));
+        assert_eq!(
+            ::chrono::DateTime::<chrono::Utc>::from_attr(Some(value)).unwrap(),
+            at
+        );
+        assert_eq!(
+            EpochSeconds(at).into_attr().n,
+            Some("1600000000".to_owned())
+        );
+
+        let date = ::chrono::NaiveDate::from_ymd_opt(2020, 9, 13).unwrap();
+        let value = date.into_attr();
+        assert_eq!(value.s, Some("2020-09-13".to_owned()));
+        assert_eq!(::chrono::NaiveDate::from_attr(Some(value)).unwrap(), date);
+        assert_eq!(
+            EpochSeconds(date).into_attr().n,
+            Some("1599955200".to_owned())
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_offset_date_time_into_attr_and_back() {
+        let at = ::time::OffsetDateTime::from_unix_timestamp_nanos(1_600_000_000_000_000_250);
+        let value = at.into_attr();
+        assert_eq!(value.s, Some("2020-09-13T12:26:40.000000250Z".to_owned()));
+        assert_eq!(::time::OffsetDateTime::from_attr(Some(value)).unwrap(), at);
+        assert_eq!(
+            EpochMillis(at).into_attr().n,
+            Some("1600000000000".to_owned())
+        );
+    }
+}
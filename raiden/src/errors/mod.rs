@@ -43,8 +43,11 @@ pub enum RaidenError {
     Blocking,
     #[error("next_token decode error")]
     NextTokenDecodeError,
-    #[error("attribute {attr_name:?} convert error")]
-    AttributeConvertError { attr_name: String },
+    #[error("attribute {attr_name:?} convert error: {source}")]
+    AttributeConvertError {
+        attr_name: String,
+        source: ConversionError,
+    },
     #[error("attribute {attr_name:?} value not found")]
     AttributeValueNotFoundError { attr_name: String },
 }
@@ -74,6 +77,34 @@ impl From<RusotoError<BatchGetItemError>> for RaidenError {
     }
 }
 
+impl From<RusotoError<BatchWriteItemError>> for RaidenError {
+    fn from(error: RusotoError<BatchWriteItemError>) -> Self {
+        match error {
+            RusotoError::Service(error) => match error {
+                BatchWriteItemError::InternalServerError(msg) => {
+                    RaidenError::InternalServerError(msg)
+                }
+                BatchWriteItemError::ItemCollectionSizeLimitExceeded(msg) => {
+                    RaidenError::ItemCollectionSizeLimitExceeded(msg)
+                }
+                BatchWriteItemError::ProvisionedThroughputExceeded(msg) => {
+                    RaidenError::ProvisionedThroughputExceeded(msg)
+                }
+                BatchWriteItemError::RequestLimitExceeded(msg) => {
+                    RaidenError::RequestLimitExceeded(msg)
+                }
+                BatchWriteItemError::ResourceNotFound(msg) => RaidenError::ResourceNotFound(msg),
+            },
+            RusotoError::HttpDispatch(e) => RaidenError::HttpDispatch(e),
+            RusotoError::Credentials(e) => RaidenError::Credentials(e),
+            RusotoError::Validation(msg) => RaidenError::Validation(msg),
+            RusotoError::ParseError(msg) => RaidenError::ParseError(msg),
+            RusotoError::Unknown(res) => RaidenError::Unknown(res),
+            RusotoError::Blocking => RaidenError::Blocking,
+        }
+    }
+}
+
 impl From<RusotoError<GetItemError>> for RaidenError {
     fn from(error: RusotoError<GetItemError>) -> Self {
         match error {
@@ -260,3 +291,31 @@ impl From<RusotoError<TransactWriteItemsError>> for RaidenError {
         }
     }
 }
+
+impl From<RusotoError<TransactGetItemsError>> for RaidenError {
+    fn from(error: RusotoError<TransactGetItemsError>) -> Self {
+        match error {
+            RusotoError::Service(error) => match error {
+                TransactGetItemsError::InternalServerError(msg) => {
+                    RaidenError::InternalServerError(msg)
+                }
+                TransactGetItemsError::ProvisionedThroughputExceeded(msg) => {
+                    RaidenError::ProvisionedThroughputExceeded(msg)
+                }
+                TransactGetItemsError::RequestLimitExceeded(msg) => {
+                    RaidenError::RequestLimitExceeded(msg)
+                }
+                TransactGetItemsError::ResourceNotFound(msg) => RaidenError::ResourceNotFound(msg),
+                TransactGetItemsError::TransactionCanceled(msg) => {
+                    RaidenError::TransactionCanceled(msg)
+                }
+            },
+            RusotoError::HttpDispatch(e) => RaidenError::HttpDispatch(e),
+            RusotoError::Credentials(e) => RaidenError::Credentials(e),
+            RusotoError::Validation(msg) => RaidenError::Validation(msg),
+            RusotoError::ParseError(msg) => RaidenError::ParseError(msg),
+            RusotoError::Unknown(res) => RaidenError::Unknown(res),
+            RusotoError::Blocking => RaidenError::Blocking,
+        }
+    }
+}
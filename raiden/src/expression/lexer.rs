@@ -0,0 +1,140 @@
+use super::ExpressionError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    // A raw attribute name, a keyword or a function name.
+    Ident(String),
+    // `#name`
+    Name(String),
+    // `:value`
+    Value(String),
+    // A list index, as `[2]`
+    Index(usize),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Dot,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Plus,
+    Minus,
+}
+
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Ident(s) | Self::Name(s) | Self::Value(s) => write!(f, "`{}`", s),
+            Self::Index(i) => write!(f, "`{}`", i),
+            Self::LParen => write!(f, "`(`"),
+            Self::RParen => write!(f, "`)`"),
+            Self::LBracket => write!(f, "`[`"),
+            Self::RBracket => write!(f, "`]`"),
+            Self::Comma => write!(f, "`,`"),
+            Self::Dot => write!(f, "`.`"),
+            Self::Eq => write!(f, "`=`"),
+            Self::Ne => write!(f, "`<>`"),
+            Self::Lt => write!(f, "`<`"),
+            Self::Le => write!(f, "`<=`"),
+            Self::Gt => write!(f, "`>`"),
+            Self::Ge => write!(f, "`>=`"),
+            Self::Plus => write!(f, "`+`"),
+            Self::Minus => write!(f, "`-`"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    // Byte offset in the expression.
+    pub position: usize,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+pub fn tokenize(expression: &str) -> Result<Vec<Token>, ExpressionError> {
+    let mut tokens = vec![];
+    let mut chars = expression.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        let kind = match c {
+            c if c.is_whitespace() => continue,
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            '[' => TokenKind::LBracket,
+            ']' => TokenKind::RBracket,
+            ',' => TokenKind::Comma,
+            '.' => TokenKind::Dot,
+            '=' => TokenKind::Eq,
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '<' => match chars.peek() {
+                Some((_, '>')) => {
+                    chars.next();
+                    TokenKind::Ne
+                }
+                Some((_, '=')) => {
+                    chars.next();
+                    TokenKind::Le
+                }
+                _ => TokenKind::Lt,
+            },
+            '>' => match chars.peek() {
+                Some((_, '=')) => {
+                    chars.next();
+                    TokenKind::Ge
+                }
+                _ => TokenKind::Gt,
+            },
+            '#' | ':' | '_' | 'a'..='z' | 'A'..='Z' | '0'..='9' => {
+                let mut word = c.to_string();
+                while let Some((_, c)) = chars.peek() {
+                    if !is_word_char(*c) {
+                        break;
+                    }
+                    word.push(*c);
+                    chars.next();
+                }
+                match c {
+                    '#' | ':' if word.len() == 1 => {
+                        return Err(ExpressionError::UnexpectedToken {
+                            position,
+                            found: format!("`{}`", word),
+                            expected: "a placeholder name".to_owned(),
+                        })
+                    }
+                    '#' => TokenKind::Name(word),
+                    ':' => TokenKind::Value(word),
+                    '0'..='9' => match word.parse::<usize>() {
+                        Ok(index) => TokenKind::Index(index),
+                        Err(_) => {
+                            return Err(ExpressionError::UnexpectedToken {
+                                position,
+                                found: format!("`{}`", word),
+                                expected: "a list index".to_owned(),
+                            })
+                        }
+                    },
+                    _ => TokenKind::Ident(word),
+                }
+            }
+            c => {
+                return Err(ExpressionError::UnexpectedToken {
+                    position,
+                    found: format!("`{}`", c),
+                    expected: "a token".to_owned(),
+                })
+            }
+        };
+        tokens.push(Token { kind, position });
+    }
+    Ok(tokens)
+}
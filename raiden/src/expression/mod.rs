@@ -0,0 +1,461 @@
+// Parses raw DynamoDB expressions into the structures raiden builds them from.
+// Placeholders are resolved with the given names and values, so the result does not depend on them.
+// See. https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.html
+mod lexer;
+mod parser;
+
+use thiserror::Error;
+
+use super::condition::Cond;
+use super::key_condition::KeyCond;
+use super::update_expression::SetOrRemove;
+use super::{
+    merge_map, AttributeNames, AttributeValues, DocumentPath, ToAttrNames, ValueIdContext,
+};
+use parser::{Parser, SetValue, UpdateOperand};
+
+// `position` is the byte offset in the expression.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ExpressionError {
+    #[error("unexpected {found} at {position}, expected {expected}")]
+    UnexpectedToken {
+        position: usize,
+        found: String,
+        expected: String,
+    },
+    #[error("unexpected end of expression at {position}, expected {expected}")]
+    UnexpectedEnd { position: usize, expected: String },
+    #[error("attribute name {name:?} at {position} is not defined")]
+    UnboundName { position: usize, name: String },
+    #[error("attribute value {name:?} at {position} is not defined")]
+    UnboundValue { position: usize, name: String },
+    #[error("{message} at {position}")]
+    Invalid { position: usize, message: String },
+}
+
+impl ExpressionError {
+    pub fn position(&self) -> usize {
+        match self {
+            Self::UnexpectedToken { position, .. }
+            | Self::UnexpectedEnd { position, .. }
+            | Self::UnboundName { position, .. }
+            | Self::UnboundValue { position, .. }
+            | Self::Invalid { position, .. } => *position,
+        }
+    }
+}
+
+impl From<ExpressionError> for super::RaidenError {
+    fn from(error: ExpressionError) -> Self {
+        super::RaidenError::Validation(error.to_string())
+    }
+}
+
+// The parsed update expression, in the same form as the fields of the update builders.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedUpdate {
+    pub set_items: Vec<SetOrRemove>,
+    pub add_items: Vec<(String, AttributeNames, AttributeValues)>,
+    pub remove_items: Vec<DocumentPath>,
+    pub delete_items: Vec<(String, AttributeNames, AttributeValues)>,
+}
+
+// Parses a condition expression or a filter expression.
+pub fn parse_condition(
+    expression: &str,
+    names: &AttributeNames,
+    values: &AttributeValues,
+) -> Result<Cond, ExpressionError> {
+    let mut parser = Parser::new(expression, names, values)?;
+    let cond = parser.condition()?;
+    parser.expect_end()?;
+    Ok(cond)
+}
+
+pub fn parse_key_condition(
+    expression: &str,
+    names: &AttributeNames,
+    values: &AttributeValues,
+) -> Result<KeyCond, ExpressionError> {
+    let mut parser = Parser::new(expression, names, values)?;
+    let cond = parser.key_condition()?;
+    parser.expect_end()?;
+    Ok(cond)
+}
+
+pub fn parse_projection(
+    expression: &str,
+    names: &AttributeNames,
+) -> Result<Vec<DocumentPath>, ExpressionError> {
+    let values: AttributeValues = std::collections::HashMap::new();
+    let mut parser = Parser::new(expression, names, &values)?;
+    let paths = parser.projection()?;
+    parser.expect_end()?;
+    Ok(paths)
+}
+
+// Parses an update expression. Values get placeholders from `:value0`.
+pub fn parse_update(
+    expression: &str,
+    names: &AttributeNames,
+    values: &AttributeValues,
+) -> Result<ParsedUpdate, ExpressionError> {
+    parse_update_with(expression, names, values, &mut ValueIdContext::new())
+}
+
+// Parses an update expression with placeholders issued by `ctx`,
+// so that it can be merged with other expressions of the same request.
+pub fn parse_update_with(
+    expression: &str,
+    names: &AttributeNames,
+    values: &AttributeValues,
+    ctx: &mut ValueIdContext,
+) -> Result<ParsedUpdate, ExpressionError> {
+    let mut parser = Parser::new(expression, names, values)?;
+    let actions = parser.update()?;
+    parser.expect_end()?;
+
+    let mut parsed = ParsedUpdate::default();
+    for (path, value) in actions.set {
+        let mut names = path.to_attr_names();
+        let mut values: AttributeValues = std::collections::HashMap::new();
+        let value = match value {
+            SetValue::Operand(operand) => render_operand(operand, ctx, &mut names, &mut values),
+            SetValue::Add(left, right) => format!(
+                "{} + {}",
+                render_operand(left, ctx, &mut names, &mut values),
+                render_operand(right, ctx, &mut names, &mut values)
+            ),
+            SetValue::Sub(left, right) => format!(
+                "{} - {}",
+                render_operand(left, ctx, &mut names, &mut values),
+                render_operand(right, ctx, &mut names, &mut values)
+            ),
+        };
+        let expression = format!("{} = {}", path, value);
+        parsed
+            .set_items
+            .push(SetOrRemove::Set(expression, names, values));
+    }
+    for (path, value) in actions.add {
+        parsed.add_items.push(render_value_action(path, value, ctx));
+    }
+    parsed.remove_items = actions.remove;
+    for (path, value) in actions.delete {
+        parsed
+            .delete_items
+            .push(render_value_action(path, value, ctx));
+    }
+    Ok(parsed)
+}
+
+fn render_operand(
+    operand: UpdateOperand,
+    ctx: &mut ValueIdContext,
+    names: &mut AttributeNames,
+    values: &mut AttributeValues,
+) -> String {
+    match operand {
+        UpdateOperand::Path(path) => {
+            *names = merge_map(std::mem::take(names), path.to_attr_names());
+            path.to_string()
+        }
+        UpdateOperand::Value(value) => {
+            let placeholder = ctx.next_placeholder();
+            values.insert(placeholder.clone(), value);
+            placeholder
+        }
+        UpdateOperand::IfNotExists(path, value) => {
+            *names = merge_map(std::mem::take(names), path.to_attr_names());
+            let value = render_operand(*value, ctx, names, values);
+            format!("if_not_exists({}, {})", path, value)
+        }
+        UpdateOperand::ListAppend(left, right) => {
+            let left = render_operand(*left, ctx, names, values);
+            let right = render_operand(*right, ctx, names, values);
+            format!("list_append({}, {})", left, right)
+        }
+    }
+}
+
+// Renders an action of ADD or DELETE, as `#path :value`.
+fn render_value_action(
+    path: DocumentPath,
+    value: super::AttributeValue,
+    ctx: &mut ValueIdContext,
+) -> (String, AttributeNames, AttributeValues) {
+    let placeholder = ctx.next_placeholder();
+    let expression = format!("{} {}", path, placeholder);
+    let mut values: AttributeValues = std::collections::HashMap::new();
+    values.insert(placeholder, value);
+    (expression, path.to_attr_names(), values)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::condition::{ConditionBuilder, ConditionFilled};
+    use crate::key_condition::{KeyConditionBuilder, KeyConditionFilled};
+    use crate::IntoAttribute;
+
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn names(pairs: &[(&str, &str)]) -> AttributeNames {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn values(pairs: Vec<(&str, crate::AttributeValue)>) -> AttributeValues {
+        pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect()
+    }
+
+    #[test]
+    fn test_parse_condition() {
+        let cond = parse_condition(
+            "attribute_exists(#a) AND #b > :v OR NOT (size(c[1]) BETWEEN :lo AND :hi)",
+            &names(&[("#a", "name"), ("#b", "age")]),
+            &values(vec![
+                (":v", 20.into_attr()),
+                (":lo", 1.into_attr()),
+                (":hi", 3.into_attr()),
+            ]),
+        )
+        .unwrap();
+        let (expression, names, values) = ConditionFilled::<()>::from_parsed(cond).build();
+        assert_eq!(
+            expression,
+            "attribute_exists(#name) AND (#age > :value0) OR (NOT (size(#c[1]) BETWEEN :value1 AND :value2))"
+                .to_owned()
+        );
+        assert_eq!(names.len(), 3);
+        assert_eq!(values[":value0"], 20.into_attr());
+        assert_eq!(values[":value2"], 3.into_attr());
+    }
+
+    #[test]
+    fn test_parse_condition_functions_and_in() {
+        let cond = parse_condition(
+            "begins_with(#a.#b, :p) and contains(tags, :t) and #a.#b in (:p, :t)",
+            &names(&[("#a", "address"), ("#b", "city")]),
+            &values(vec![(":p", "fo".into_attr()), (":t", "foo".into_attr())]),
+        )
+        .unwrap();
+        let mut item: AttributeValues = std::collections::HashMap::new();
+        let mut address: AttributeValues = std::collections::HashMap::new();
+        address.insert("city".to_owned(), "foo".into_attr());
+        item.insert(
+            "address".to_owned(),
+            crate::AttributeValue {
+                m: Some(address),
+                ..crate::AttributeValue::default()
+            },
+        );
+        item.insert("tags".to_owned(), vec!["foo".to_owned()].into_attr());
+        assert!(cond.evaluate(&item));
+    }
+
+    #[test]
+    fn test_parse_key_condition() {
+        let cond = parse_key_condition(
+            "#id = :id AND begins_with(#year, :y)",
+            &names(&[("#id", "id"), ("#year", "year")]),
+            &values(vec![(":id", "id0".into_attr()), (":y", "20".into_attr())]),
+        )
+        .unwrap();
+        let (expression, _names, values) = KeyConditionFilled::<()>::from_parsed(cond).build();
+        assert_eq!(
+            expression,
+            "#id = :value0 AND (begins_with(#year, :value1))".to_owned()
+        );
+        assert_eq!(values[":value1"], "20".into_attr());
+    }
+
+    #[test]
+    fn test_parse_update() {
+        let parsed = parse_update(
+            "SET #a = if_not_exists(#a, :zero) + :one, #l = list_append(#l, :l) REMOVE #b[2], c ADD #n :one DELETE #s :s",
+            &names(&[("#a", "count"), ("#l", "logs"), ("#b", "list"), ("#n", "num"), ("#s", "set")]),
+            &values(vec![
+                (":zero", 0.into_attr()),
+                (":one", 1.into_attr()),
+                (":l", vec!["x".to_owned()].into_attr()),
+                (":s", "x".into_attr()),
+            ]),
+        )
+        .unwrap();
+        let set = parsed
+            .set_items
+            .into_iter()
+            .map(|item| match item {
+                SetOrRemove::Set(expression, _, _) => expression,
+                SetOrRemove::Remove(expression, _) => expression,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            set,
+            vec![
+                "#count = if_not_exists(#count, :value0) + :value1".to_owned(),
+                "#logs = list_append(#logs, :value2)".to_owned(),
+            ]
+        );
+        let remove = parsed
+            .remove_items
+            .iter()
+            .map(|path| path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(remove, vec!["#list[2]".to_owned(), "#c".to_owned()]);
+        assert_eq!(parsed.add_items[0].0, "#num :value3".to_owned());
+        assert_eq!(parsed.delete_items[0].0, "#set :value4".to_owned());
+    }
+
+    #[test]
+    fn test_parse_with_invalid_placeholder_chars() {
+        let cond = parse_condition(
+            "#u = :v",
+            &names(&[("#u", "user-id")]),
+            &values(vec![(":v", "id0".into_attr())]),
+        )
+        .unwrap();
+        let (expression, names_, _values) = ConditionFilled::<()>::from_parsed(cond).build();
+        assert_eq!(expression, "#_user_2did = :value0".to_owned());
+        assert_eq!(names_, names(&[("#_user_2did", "user-id")]));
+
+        // A name which looks like an escaped one is kept apart from it.
+        let cond = parse_condition(
+            "#u = :v AND #e = :v",
+            &names(&[("#u", "user-id"), ("#e", "user_2did")]),
+            &values(vec![(":v", "id0".into_attr())]),
+        )
+        .unwrap();
+        let (expression, names_, _values) = ConditionFilled::<()>::from_parsed(cond).build();
+        assert_eq!(
+            expression,
+            "#_user_2did = :value0 AND (#user_2did = :value1)".to_owned()
+        );
+        assert_eq!(
+            names_,
+            names(&[("#_user_2did", "user-id"), ("#user_2did", "user_2did")])
+        );
+
+        let parsed = parse_update(
+            "SET #u = :v",
+            &names(&[("#u", "user-id")]),
+            &values(vec![(":v", "id0".into_attr())]),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.set_items,
+            vec![SetOrRemove::Set(
+                "#_user_2did = :value0".to_owned(),
+                names(&[("#_user_2did", "user-id")]),
+                values(vec![(":value0", "id0".into_attr())]),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_projection() {
+        let paths = parse_projection("#a, b.c[0]", &names(&[("#a", "name")])).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                DocumentPath::new("name"),
+                DocumentPath::new("b").field("c").index(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let empty_names = names(&[]);
+        let empty_values = values(vec![]);
+        assert_eq!(
+            parse_condition("#a = :v", &empty_names, &empty_values).unwrap_err(),
+            ExpressionError::UnboundName {
+                position: 0,
+                name: "#a".to_owned()
+            }
+        );
+        assert_eq!(
+            parse_condition("a = :v", &empty_names, &empty_values).unwrap_err(),
+            ExpressionError::UnboundValue {
+                position: 4,
+                name: ":v".to_owned()
+            }
+        );
+        assert_eq!(
+            parse_condition("a = b c", &empty_names, &empty_values)
+                .unwrap_err()
+                .position(),
+            6
+        );
+        assert_eq!(
+            parse_condition("attribute_exists(a", &empty_names, &empty_values).unwrap_err(),
+            ExpressionError::UnexpectedEnd {
+                position: 18,
+                expected: "`)`".to_owned()
+            }
+        );
+        assert_eq!(
+            parse_condition("a ! b", &empty_names, &empty_values)
+                .unwrap_err()
+                .position(),
+            2
+        );
+        assert_eq!(
+            parse_update("SET a = b SET c = d", &empty_names, &empty_values)
+                .unwrap_err()
+                .position(),
+            10
+        );
+        assert_eq!(
+            parse_key_condition("a <> b", &empty_names, &empty_values)
+                .unwrap_err()
+                .position(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_parse_nesting_limit() {
+        let empty_names = names(&[]);
+        let v = values(vec![(":v", 1.into_attr())]);
+        let nested = |open: &str, close: &str, inner: &str, depth: usize| {
+            format!("{}{}{}", open.repeat(depth), inner, close.repeat(depth))
+        };
+        assert!(parse_condition(&nested("(", ")", "a = :v", 64), &empty_names, &v).is_ok());
+        assert!(parse_condition(&nested("NOT ", "", "a = :v", 64), &empty_names, &v).is_ok());
+
+        let expected = ExpressionError::Invalid {
+            position: 65,
+            message: "expression is nested more than 64 levels".to_owned(),
+        };
+        let deep = nested("(", ")", "a = :v", 100_000);
+        assert_eq!(
+            parse_condition(&deep, &empty_names, &v).unwrap_err(),
+            expected
+        );
+        assert_eq!(
+            parse_key_condition(&deep, &empty_names, &v).unwrap_err(),
+            expected
+        );
+        assert_eq!(
+            parse_condition(&nested("NOT ", "", "a = :v", 100_000), &empty_names, &v).unwrap_err(),
+            ExpressionError::Invalid {
+                position: 65 * 4,
+                message: "expression is nested more than 64 levels".to_owned(),
+            }
+        );
+        let update = format!("SET a = {}", nested("list_append(", ", :v)", ":v", 100_000));
+        assert_eq!(
+            parse_update(&update, &empty_names, &v)
+                .unwrap_err()
+                .to_string(),
+            "expression is nested more than 64 levels at 788"
+        );
+    }
+}
@@ -0,0 +1,558 @@
+use super::lexer::{tokenize, Token, TokenKind};
+use super::ExpressionError;
+use crate::condition::{
+    AttrOrPlaceholder, Cond, ConditionComparisonExpression, ConditionFunctionExpression,
+};
+use crate::key_condition::{KeyCond, KeyConditionTypes};
+use crate::{AttributeNames, AttributeType, AttributeValue, AttributeValues, DocumentPath};
+
+// Reserved words can not be used as raw attribute names.
+const KEYWORDS: [&str; 9] = [
+    "AND", "OR", "NOT", "BETWEEN", "IN", "SET", "REMOVE", "ADD", "DELETE",
+];
+
+const CLAUSES: [&str; 4] = ["SET", "REMOVE", "ADD", "DELETE"];
+
+// Parentheses, `NOT` and functions can be nested up to this depth,
+// so that a malicious expression does not overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+// An operand of a SET action.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateOperand {
+    Path(DocumentPath),
+    Value(AttributeValue),
+    IfNotExists(DocumentPath, Box<UpdateOperand>),
+    ListAppend(Box<UpdateOperand>, Box<UpdateOperand>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetValue {
+    Operand(UpdateOperand),
+    Add(UpdateOperand, UpdateOperand),
+    Sub(UpdateOperand, UpdateOperand),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UpdateActions {
+    pub set: Vec<(DocumentPath, SetValue)>,
+    pub remove: Vec<DocumentPath>,
+    pub add: Vec<(DocumentPath, AttributeValue)>,
+    pub delete: Vec<(DocumentPath, AttributeValue)>,
+}
+
+pub struct Parser<'a> {
+    tokens: Vec<Token>,
+    cursor: usize,
+    len: usize,
+    depth: usize,
+    names: &'a AttributeNames,
+    values: &'a AttributeValues,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(
+        expression: &str,
+        names: &'a AttributeNames,
+        values: &'a AttributeValues,
+    ) -> Result<Self, ExpressionError> {
+        Ok(Self {
+            tokens: tokenize(expression)?,
+            cursor: 0,
+            len: expression.len(),
+            depth: 0,
+            names,
+            values,
+        })
+    }
+
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.cursor).map(|token| &token.kind)
+    }
+
+    fn peek_at(&self, n: usize) -> Option<&TokenKind> {
+        self.tokens.get(self.cursor + n).map(|token| &token.kind)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.cursor)
+            .map_or(self.len, |token| token.position)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.cursor).cloned();
+        self.cursor += 1;
+        token
+    }
+
+    fn unexpected(&self, expected: &str) -> ExpressionError {
+        match self.tokens.get(self.cursor) {
+            Some(token) => ExpressionError::UnexpectedToken {
+                position: token.position,
+                found: token.kind.to_string(),
+                expected: expected.to_owned(),
+            },
+            None => ExpressionError::UnexpectedEnd {
+                position: self.len,
+                expected: expected.to_owned(),
+            },
+        }
+    }
+
+    fn expect(&mut self, kind: TokenKind, expected: &str) -> Result<(), ExpressionError> {
+        if self.peek() == Some(&kind) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(TokenKind::Ident(s)) if s.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.peek_keyword(keyword);
+        if found {
+            self.advance();
+        }
+        found
+    }
+
+    // Runs `f` one level deeper, failing if the expression is nested too deeply.
+    fn nested<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, ExpressionError>,
+    ) -> Result<T, ExpressionError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(ExpressionError::Invalid {
+                position: self.position(),
+                message: format!("expression is nested more than {} levels", MAX_DEPTH),
+            });
+        }
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
+    // Returns the name of the function if a function call follows.
+    fn peek_function(&self, functions: &[&str]) -> Option<String> {
+        match (self.peek(), self.peek_at(1)) {
+            (Some(TokenKind::Ident(name)), Some(TokenKind::LParen))
+                if functions.contains(&name.as_str()) =>
+            {
+                Some(name.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn expect_end(&self) -> Result<(), ExpressionError> {
+        match self.peek() {
+            Some(_) => Err(self.unexpected("the end of the expression")),
+            None => Ok(()),
+        }
+    }
+
+    fn attr_name(&mut self) -> Result<String, ExpressionError> {
+        let position = self.position();
+        match self.peek().cloned() {
+            Some(TokenKind::Name(name)) => {
+                self.advance();
+                self.names
+                    .get(&name)
+                    .cloned()
+                    .ok_or(ExpressionError::UnboundName { position, name })
+            }
+            Some(TokenKind::Ident(name))
+                if !KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(&name)) =>
+            {
+                self.advance();
+                Ok(name)
+            }
+            _ => Err(self.unexpected("an attribute name")),
+        }
+    }
+
+    pub fn path(&mut self) -> Result<DocumentPath, ExpressionError> {
+        let mut path = DocumentPath::new(self.attr_name()?);
+        loop {
+            match self.peek() {
+                Some(TokenKind::Dot) => {
+                    self.advance();
+                    path = path.field(self.attr_name()?);
+                }
+                Some(TokenKind::LBracket) => {
+                    self.advance();
+                    match self.peek().cloned() {
+                        Some(TokenKind::Index(index)) => {
+                            self.advance();
+                            path = path.index(index);
+                        }
+                        _ => return Err(self.unexpected("a list index")),
+                    }
+                    self.expect(TokenKind::RBracket, "`]`")?;
+                }
+                _ => return Ok(path),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<AttributeValue, ExpressionError> {
+        let position = self.position();
+        match self.peek().cloned() {
+            Some(TokenKind::Value(name)) => {
+                self.advance();
+                self.values
+                    .get(&name)
+                    .cloned()
+                    .ok_or(ExpressionError::UnboundValue { position, name })
+            }
+            _ => Err(self.unexpected("an attribute value")),
+        }
+    }
+
+    fn string_value(&mut self, function: &str) -> Result<String, ExpressionError> {
+        let position = self.position();
+        self.value()?.s.ok_or_else(|| ExpressionError::Invalid {
+            position,
+            message: format!("`{}` requires a string value", function),
+        })
+    }
+
+    fn prefix_value(&mut self, function: &str) -> Result<AttributeValue, ExpressionError> {
+        let position = self.position();
+        let value = self.value()?;
+        if value.s.is_none() && value.b.is_none() {
+            return Err(ExpressionError::Invalid {
+                position,
+                message: format!("`{}` requires a string or binary value", function),
+            });
+        }
+        Ok(value)
+    }
+
+    // condition ::= and_condition (OR and_condition)*
+    pub fn condition(&mut self) -> Result<Cond, ExpressionError> {
+        let mut left = self.and_condition()?;
+        while self.eat_keyword("OR") {
+            let right = self.and_condition()?;
+            left = Cond::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    // and_condition ::= not_condition (AND not_condition)*
+    fn and_condition(&mut self) -> Result<Cond, ExpressionError> {
+        let mut left = self.not_condition()?;
+        while self.eat_keyword("AND") {
+            let right = self.not_condition()?;
+            left = Cond::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    // not_condition ::= NOT not_condition | primary_condition
+    fn not_condition(&mut self) -> Result<Cond, ExpressionError> {
+        if self.eat_keyword("NOT") {
+            let cond = self.nested(Self::not_condition)?;
+            Ok(Cond::Not(Box::new(cond)))
+        } else {
+            self.primary_condition()
+        }
+    }
+
+    fn primary_condition(&mut self) -> Result<Cond, ExpressionError> {
+        if self.peek() == Some(&TokenKind::LParen) {
+            self.advance();
+            let cond = self.nested(Self::condition)?;
+            self.expect(TokenKind::RParen, "`)`")?;
+            return Ok(cond);
+        }
+        let functions = [
+            "attribute_exists",
+            "attribute_not_exists",
+            "attribute_type",
+            "begins_with",
+            "contains",
+        ];
+        if let Some(function) = self.peek_function(&functions) {
+            return self.function(&function);
+        }
+        let left = self.operand()?;
+        self.comparison(left)
+    }
+
+    fn function(&mut self, function: &str) -> Result<Cond, ExpressionError> {
+        self.advance();
+        self.expect(TokenKind::LParen, "`(`")?;
+        let path = self.path()?;
+        let func = match function {
+            "attribute_exists" => ConditionFunctionExpression::AttributeExists(path),
+            "attribute_not_exists" => ConditionFunctionExpression::AttributeNotExists(path),
+            "attribute_type" => {
+                self.expect(TokenKind::Comma, "`,`")?;
+                let position = self.position();
+                let t = self.string_value(function)?;
+                let t = parse_attribute_type(&t).ok_or_else(|| ExpressionError::Invalid {
+                    position,
+                    message: format!("`{}` is not an attribute type", t),
+                })?;
+                ConditionFunctionExpression::AttributeType(path, t)
+            }
+            "begins_with" => {
+                self.expect(TokenKind::Comma, "`,`")?;
+                ConditionFunctionExpression::BeginsWith(path, self.prefix_value(function)?)
+            }
+            _ => {
+                self.expect(TokenKind::Comma, "`,`")?;
+                ConditionFunctionExpression::Contains(path, self.value()?)
+            }
+        };
+        self.expect(TokenKind::RParen, "`)`")?;
+        Ok(Cond::Func(func))
+    }
+
+    // operand ::= :value | size(path) | path
+    fn operand(&mut self) -> Result<AttrOrPlaceholder, ExpressionError> {
+        match self.peek() {
+            Some(TokenKind::Value(_)) => Ok(AttrOrPlaceholder::Placeholder(self.value()?)),
+            _ if self.peek_function(&["size"]).is_some() => {
+                self.advance();
+                self.advance();
+                let path = self.path()?;
+                self.expect(TokenKind::RParen, "`)`")?;
+                Ok(AttrOrPlaceholder::Size(path))
+            }
+            _ => Ok(AttrOrPlaceholder::Attr(self.path()?)),
+        }
+    }
+
+    fn comparison(&mut self, left: AttrOrPlaceholder) -> Result<Cond, ExpressionError> {
+        let cmp = match self.peek().cloned() {
+            Some(TokenKind::Eq) => {
+                self.advance();
+                ConditionComparisonExpression::Eq(left, self.operand()?)
+            }
+            Some(TokenKind::Ne) => {
+                self.advance();
+                ConditionComparisonExpression::Ne(left, self.operand()?)
+            }
+            Some(TokenKind::Lt) => {
+                self.advance();
+                ConditionComparisonExpression::Lt(left, self.operand()?)
+            }
+            Some(TokenKind::Le) => {
+                self.advance();
+                ConditionComparisonExpression::Le(left, self.operand()?)
+            }
+            Some(TokenKind::Gt) => {
+                self.advance();
+                ConditionComparisonExpression::Gt(left, self.operand()?)
+            }
+            Some(TokenKind::Ge) => {
+                self.advance();
+                ConditionComparisonExpression::Ge(left, self.operand()?)
+            }
+            _ if self.eat_keyword("BETWEEN") => {
+                let lower = self.operand()?;
+                if !self.eat_keyword("AND") {
+                    return Err(self.unexpected("`AND`"));
+                }
+                ConditionComparisonExpression::Between(left, lower, self.operand()?)
+            }
+            _ if self.eat_keyword("IN") => {
+                self.expect(TokenKind::LParen, "`(`")?;
+                let mut list = vec![self.operand()?];
+                while self.peek() == Some(&TokenKind::Comma) {
+                    self.advance();
+                    list.push(self.operand()?);
+                }
+                self.expect(TokenKind::RParen, "`)`")?;
+                ConditionComparisonExpression::In(left, list)
+            }
+            _ => return Err(self.unexpected("a comparator, `BETWEEN` or `IN`")),
+        };
+        Ok(Cond::Cmp(cmp))
+    }
+
+    // key_condition ::= key_primary (AND key_primary)*
+    pub fn key_condition(&mut self) -> Result<KeyCond, ExpressionError> {
+        let mut left = self.key_primary()?;
+        while self.eat_keyword("AND") {
+            let right = self.key_primary()?;
+            left = KeyCond::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn key_primary(&mut self) -> Result<KeyCond, ExpressionError> {
+        if self.peek() == Some(&TokenKind::LParen) {
+            self.advance();
+            let cond = self.nested(Self::key_condition)?;
+            self.expect(TokenKind::RParen, "`)`")?;
+            return Ok(cond);
+        }
+        if self.peek_function(&["begins_with"]).is_some() {
+            self.advance();
+            self.advance();
+            let path = self.path()?;
+            self.expect(TokenKind::Comma, "`,`")?;
+            let value = self.value()?;
+            self.expect(TokenKind::RParen, "`)`")?;
+            return Ok(KeyCond::Cmp(path, KeyConditionTypes::BeginsWith(value)));
+        }
+        let path = self.path()?;
+        let cond = match self.peek().cloned() {
+            Some(TokenKind::Eq) => {
+                self.advance();
+                KeyConditionTypes::Eq(self.value()?)
+            }
+            Some(TokenKind::Lt) => {
+                self.advance();
+                KeyConditionTypes::Lt(self.value()?)
+            }
+            Some(TokenKind::Le) => {
+                self.advance();
+                KeyConditionTypes::Le(self.value()?)
+            }
+            Some(TokenKind::Gt) => {
+                self.advance();
+                KeyConditionTypes::Gt(self.value()?)
+            }
+            Some(TokenKind::Ge) => {
+                self.advance();
+                KeyConditionTypes::Ge(self.value()?)
+            }
+            _ if self.eat_keyword("BETWEEN") => {
+                let lower = self.value()?;
+                if !self.eat_keyword("AND") {
+                    return Err(self.unexpected("`AND`"));
+                }
+                KeyConditionTypes::Between(lower, self.value()?)
+            }
+            _ => return Err(self.unexpected("`=`, `<`, `<=`, `>`, `>=` or `BETWEEN`")),
+        };
+        Ok(KeyCond::Cmp(path, cond))
+    }
+
+    // update ::= (SET action, ... | REMOVE path, ... | ADD path :value, ... | DELETE path :value, ...)+
+    pub fn update(&mut self) -> Result<UpdateActions, ExpressionError> {
+        let mut actions = UpdateActions::default();
+        let mut clauses: Vec<String> = vec![];
+        loop {
+            let position = self.position();
+            let clause = match self.peek() {
+                Some(TokenKind::Ident(s)) if CLAUSES.iter().any(|c| c.eq_ignore_ascii_case(s)) => {
+                    s.to_ascii_uppercase()
+                }
+                _ => return Err(self.unexpected("`SET`, `REMOVE`, `ADD` or `DELETE`")),
+            };
+            if clauses.contains(&clause) {
+                return Err(ExpressionError::Invalid {
+                    position,
+                    message: format!("`{}` clause appears more than once", clause),
+                });
+            }
+            self.advance();
+            loop {
+                let path = self.path()?;
+                match clause.as_str() {
+                    "SET" => {
+                        self.expect(TokenKind::Eq, "`=`")?;
+                        let value = self.set_value()?;
+                        actions.set.push((path, value));
+                    }
+                    "REMOVE" => actions.remove.push(path),
+                    "ADD" => actions.add.push((path, self.value()?)),
+                    _ => actions.delete.push((path, self.value()?)),
+                }
+                if self.peek() != Some(&TokenKind::Comma) {
+                    break;
+                }
+                self.advance();
+            }
+            clauses.push(clause);
+            if self.peek().is_none() {
+                return Ok(actions);
+            }
+        }
+    }
+
+    // set_value ::= update_operand ((+ | -) update_operand)?
+    fn set_value(&mut self) -> Result<SetValue, ExpressionError> {
+        let left = self.update_operand()?;
+        match self.peek() {
+            Some(TokenKind::Plus) => {
+                self.advance();
+                Ok(SetValue::Add(left, self.update_operand()?))
+            }
+            Some(TokenKind::Minus) => {
+                self.advance();
+                Ok(SetValue::Sub(left, self.update_operand()?))
+            }
+            _ => Ok(SetValue::Operand(left)),
+        }
+    }
+
+    // update_operand ::= :value | if_not_exists(path, update_operand) | list_append(update_operand, update_operand) | path
+    fn update_operand(&mut self) -> Result<UpdateOperand, ExpressionError> {
+        if let Some(TokenKind::Value(_)) = self.peek() {
+            return Ok(UpdateOperand::Value(self.value()?));
+        }
+        match self
+            .peek_function(&["if_not_exists", "list_append"])
+            .as_deref()
+        {
+            Some("if_not_exists") => {
+                self.advance();
+                self.advance();
+                let path = self.path()?;
+                self.expect(TokenKind::Comma, "`,`")?;
+                let value = self.nested(Self::update_operand)?;
+                self.expect(TokenKind::RParen, "`)`")?;
+                Ok(UpdateOperand::IfNotExists(path, Box::new(value)))
+            }
+            Some(_) => {
+                self.advance();
+                self.advance();
+                let left = self.nested(Self::update_operand)?;
+                self.expect(TokenKind::Comma, "`,`")?;
+                let right = self.nested(Self::update_operand)?;
+                self.expect(TokenKind::RParen, "`)`")?;
+                Ok(UpdateOperand::ListAppend(Box::new(left), Box::new(right)))
+            }
+            None => Ok(UpdateOperand::Path(self.path()?)),
+        }
+    }
+
+    // projection ::= path (, path)*
+    pub fn projection(&mut self) -> Result<Vec<DocumentPath>, ExpressionError> {
+        let mut paths = vec![self.path()?];
+        while self.peek() == Some(&TokenKind::Comma) {
+            self.advance();
+            paths.push(self.path()?);
+        }
+        Ok(paths)
+    }
+}
+
+fn parse_attribute_type(t: &str) -> Option<AttributeType> {
+    match t {
+        "S" => Some(AttributeType::S),
+        "SS" => Some(AttributeType::SS),
+        "N" => Some(AttributeType::N),
+        "NS" => Some(AttributeType::NS),
+        "B" => Some(AttributeType::B),
+        "BS" => Some(AttributeType::BS),
+        "BOOL" => Some(AttributeType::BOOL),
+        "NULL" => Some(AttributeType::NULL),
+        "L" => Some(AttributeType::L),
+        "M" => Some(AttributeType::M),
+        _ => None,
+    }
+}
@@ -0,0 +1,127 @@
+// Free-form JSON as an attribute, e.g. for schemaless metadata.
+// Empty arrays and objects are stored as `NULL` like `Vec` and maps,
+// so they are read back as `Value::Null`.
+use super::{AttributeValue, ConversionError, FromAttribute, IntoAttribute};
+use serde_json::{Map, Number, Value};
+
+fn null() -> AttributeValue {
+    AttributeValue {
+        null: Some(true),
+        ..AttributeValue::default()
+    }
+}
+
+fn parse_number(n: &str) -> Result<Value, ConversionError> {
+    let number = if let Ok(n) = n.parse::<i64>() {
+        Number::from(n)
+    } else if let Ok(n) = n.parse::<u64>() {
+        Number::from(n)
+    } else {
+        n.parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .ok_or_else(|| {
+                ConversionError::ParseNumber(n.to_owned(), "serde_json::Number".to_owned())
+            })?
+    };
+    Ok(Value::Number(number))
+}
+
+impl IntoAttribute for Value {
+    fn into_attr(self) -> AttributeValue {
+        match self {
+            Value::Null => null(),
+            Value::Bool(b) => b.into_attr(),
+            Value::Number(n) => AttributeValue {
+                n: Some(n.to_string()),
+                ..AttributeValue::default()
+            },
+            Value::String(s) => s.into_attr(),
+            // See. https://github.com/raiden-rs/raiden/issues/57
+            Value::Array(a) => a.into_attr(),
+            Value::Object(o) => {
+                if o.is_empty() {
+                    // See. https://github.com/raiden-rs/raiden/issues/57
+                    return null();
+                }
+                AttributeValue {
+                    m: Some(o.into_iter().map(|(k, v)| (k, v.into_attr())).collect()),
+                    ..AttributeValue::default()
+                }
+            }
+        }
+    }
+}
+
+impl FromAttribute for Value {
+    fn from_attr(value: Option<AttributeValue>) -> Result<Self, ConversionError> {
+        let value = match value {
+            Some(value) => value,
+            None => return Ok(Value::Null),
+        };
+        if let Some(true) = value.null {
+            return Ok(Value::Null);
+        }
+        if let Some(s) = value.s {
+            return Ok(Value::String(s));
+        }
+        if let Some(n) = value.n {
+            return parse_number(&n);
+        }
+        if let Some(b) = value.bool {
+            return Ok(Value::Bool(b));
+        }
+        if let Some(l) = value.l {
+            return l
+                .into_iter()
+                .map(|v| Value::from_attr(Some(v)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array);
+        }
+        if let Some(m) = value.m {
+            return m
+                .into_iter()
+                .map(|(k, v)| match Value::from_attr(Some(v)) {
+                    Ok(v) => Ok((k, v)),
+                    Err(e) => Err(ConversionError::Field(k, Box::new(e))),
+                })
+                .collect::<Result<Map<_, _>, _>>()
+                .map(Value::Object);
+        }
+        if let Some(ss) = value.ss {
+            return Ok(Value::Array(ss.into_iter().map(Value::String).collect()));
+        }
+        if let Some(ns) = value.ns {
+            return ns
+                .iter()
+                .map(|n| parse_number(n))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array);
+        }
+        Err(ConversionError::ValueIsNone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_into_attr_and_back() {
+        let json = serde_json::json!({
+            "count": -3,
+            "nested": { "list": [1.5, "x", null, false] },
+        });
+        let value = json.clone().into_attr();
+        assert_eq!(value.m.as_ref().unwrap()["count"].n, Some("-3".to_owned()));
+        assert_eq!(serde_json::Value::from_attr(Some(value)).unwrap(), json);
+
+        // Empty arrays and objects are stored as `NULL`, so they are read back as `null`.
+        let json = serde_json::json!({ "name": "", "list": [], "map": {} });
+        let value = json.into_attr();
+        assert_eq!(
+            serde_json::Value::from_attr(Some(value)).unwrap(),
+            serde_json::json!({ "name": "", "list": null, "map": null })
+        );
+    }
+}
@@ -1,215 +1,159 @@
 pub type KeyConditionString = String;
 
-#[derive(Debug, Clone)]
-pub enum KeyConditionConjunction {
-    And(
-        KeyConditionString,
-        super::AttributeNames,
-        super::AttributeValues,
-    ),
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyConditionTypes {
+    Eq(super::AttributeValue),
+    Le(super::AttributeValue),
+    Ge(super::AttributeValue),
+    Lt(super::AttributeValue),
+    Gt(super::AttributeValue),
+    Between(super::AttributeValue, super::AttributeValue),
+    BeginsWith(super::AttributeValue),
 }
 
+// Values are held as is, and get their placeholders when the key condition is built.
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
-pub enum KeyConditionTypes {
-    Eq(super::Placeholder, super::AttributeValue),
-    Le(super::Placeholder, super::AttributeValue),
-    Ge(super::Placeholder, super::AttributeValue),
-    Lt(super::Placeholder, super::AttributeValue),
-    Gt(super::Placeholder, super::AttributeValue),
-    Between(
-        super::Placeholder,
-        super::AttributeValue,
-        super::Placeholder,
-        super::AttributeValue,
-    ),
-    BeginsWith(super::Placeholder, super::AttributeValue),
+pub enum KeyCond {
+    Cmp(super::DocumentPath, KeyConditionTypes),
+    And(Box<KeyCond>, Box<KeyCond>),
+}
+
+impl KeyCond {
+    fn build(
+        self,
+        ctx: &mut super::ValueIdContext,
+        names: &mut super::AttributeNames,
+        values: &mut super::AttributeValues,
+    ) -> KeyConditionString {
+        use super::ToAttrNames;
+        match self {
+            Self::Cmp(attr, cond) => {
+                *names = super::merge_map(std::mem::take(names), attr.to_attr_names());
+                let mut placeholder = |value: super::AttributeValue| {
+                    let placeholder = ctx.next_placeholder();
+                    values.insert(placeholder.clone(), value);
+                    placeholder
+                };
+                match cond {
+                    KeyConditionTypes::Eq(value) => format!("{} = {}", attr, placeholder(value)),
+                    KeyConditionTypes::Gt(value) => format!("{} > {}", attr, placeholder(value)),
+                    KeyConditionTypes::Ge(value) => format!("{} >= {}", attr, placeholder(value)),
+                    KeyConditionTypes::Le(value) => format!("{} <= {}", attr, placeholder(value)),
+                    KeyConditionTypes::Lt(value) => format!("{} < {}", attr, placeholder(value)),
+                    KeyConditionTypes::Between(value1, value2) => {
+                        let placeholder1 = placeholder(value1);
+                        let placeholder2 = placeholder(value2);
+                        format!("{} BETWEEN {} AND {}", attr, placeholder1, placeholder2)
+                    }
+                    KeyConditionTypes::BeginsWith(value) => {
+                        format!("begins_with({}, {})", attr, placeholder(value))
+                    }
+                }
+            }
+            Self::And(left, right) => {
+                let left = left.build(ctx, names, values);
+                let right = right.build(ctx, names, values);
+                format!("{} AND ({})", left, right)
+            }
+        }
+    }
 }
 
 pub trait KeyConditionBuilder<T> {
+    fn into_key_cond(self) -> KeyCond;
+
     fn build(
         self,
     ) -> (
         KeyConditionString,
         super::AttributeNames,
         super::AttributeValues,
-    );
+    )
+    where
+        Self: Sized,
+    {
+        self.build_with(&mut super::ValueIdContext::new())
+    }
+
+    // Builds the key condition with placeholders issued by `ctx`,
+    // so that it can be merged with other expressions of the same request.
+    fn build_with(
+        self,
+        ctx: &mut super::ValueIdContext,
+    ) -> (
+        KeyConditionString,
+        super::AttributeNames,
+        super::AttributeValues,
+    )
+    where
+        Self: Sized,
+    {
+        let mut names: super::AttributeNames = std::collections::HashMap::new();
+        let mut values: super::AttributeValues = std::collections::HashMap::new();
+        let expression = self.into_key_cond().build(ctx, &mut names, &mut values);
+        (expression, names, values)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct KeyCondition<T> {
-    pub attr: String,
+    pub attr: super::DocumentPath,
     pub _token: std::marker::PhantomData<T>,
 }
 
 #[derive(Debug, Clone)]
 pub struct KeyConditionFilledOrWaitConjunction<T> {
-    attr: String,
+    attr: super::DocumentPath,
     cond: KeyConditionTypes,
     _token: std::marker::PhantomData<T>,
 }
 
 #[derive(Debug, Clone)]
 pub struct KeyConditionFilled<T> {
-    attr: String,
-    cond: KeyConditionTypes,
-    conjunction: KeyConditionConjunction,
+    cond: KeyCond,
     _token: std::marker::PhantomData<T>,
 }
 
+impl<T> KeyConditionFilled<T> {
+    // Wraps a `KeyCond` which is not built from a typed builder, e.g. parsed by `expression::parse_key_condition`.
+    // Its attributes are not checked against the struct of `T`, so this has to be chosen explicitly.
+    pub fn from_parsed(cond: KeyCond) -> Self {
+        KeyConditionFilled {
+            cond,
+            _token: std::marker::PhantomData,
+        }
+    }
+}
+
 impl<T> KeyConditionFilledOrWaitConjunction<T> {
     pub fn and(self, cond: impl KeyConditionBuilder<T>) -> KeyConditionFilled<T> {
-        let (condition_string, attr_names, attr_values) = cond.build();
         KeyConditionFilled {
-            attr: self.attr,
-            cond: self.cond,
-            conjunction: KeyConditionConjunction::And(condition_string, attr_names, attr_values),
+            cond: KeyCond::And(
+                Box::new(KeyCond::Cmp(self.attr, self.cond)),
+                Box::new(cond.into_key_cond()),
+            ),
             _token: self._token,
         }
     }
 }
 
 impl<T> KeyConditionBuilder<T> for KeyConditionFilledOrWaitConjunction<T> {
-    fn build(self) -> (String, super::AttributeNames, super::AttributeValues) {
-        let attr_name = self.attr;
-        let mut attr_names: super::AttributeNames = std::collections::HashMap::new();
-        let mut attr_values: super::AttributeValues = std::collections::HashMap::new();
-
-        attr_names.insert(format!("#{}", attr_name), attr_name.clone());
-        match self.cond {
-            super::key_condition::KeyConditionTypes::Eq(placeholder, value) => {
-                attr_values.insert(placeholder.to_string(), value);
-                (
-                    format!("#{} = {}", attr_name, placeholder),
-                    attr_names,
-                    attr_values,
-                )
-            }
-            super::key_condition::KeyConditionTypes::Gt(placeholder, value) => {
-                attr_values.insert(placeholder.to_string(), value);
-                (
-                    format!("#{} > {}", attr_name, placeholder),
-                    attr_names,
-                    attr_values,
-                )
-            }
-            super::key_condition::KeyConditionTypes::Ge(placeholder, value) => {
-                attr_values.insert(placeholder.to_string(), value);
-                (
-                    format!("#{} >= {}", attr_name, placeholder),
-                    attr_names,
-                    attr_values,
-                )
-            }
-            super::key_condition::KeyConditionTypes::Le(placeholder, value) => {
-                attr_values.insert(placeholder.to_string(), value);
-                (
-                    format!("#{} <= {}", attr_name, placeholder),
-                    attr_names,
-                    attr_values,
-                )
-            }
-            super::key_condition::KeyConditionTypes::Lt(placeholder, value) => {
-                attr_values.insert(placeholder.to_string(), value);
-                (
-                    format!("#{} < {}", attr_name, placeholder),
-                    attr_names,
-                    attr_values,
-                )
-            }
-            super::key_condition::KeyConditionTypes::Between(
-                placeholder1,
-                value1,
-                placeholder2,
-                value2,
-            ) => {
-                attr_values.insert(placeholder1.to_string(), value1);
-                attr_values.insert(placeholder2.to_string(), value2);
-                (
-                    format!(
-                        "#{} BETWEEN {} AND {}",
-                        attr_name, placeholder1, placeholder2
-                    ),
-                    attr_names,
-                    attr_values,
-                )
-            }
-            super::key_condition::KeyConditionTypes::BeginsWith(placeholder, value) => {
-                attr_values.insert(placeholder.to_string(), value);
-                (
-                    format!("begins_with(#{}, {})", attr_name, placeholder),
-                    attr_names,
-                    attr_values,
-                )
-            }
-        }
+    fn into_key_cond(self) -> KeyCond {
+        KeyCond::Cmp(self.attr, self.cond)
     }
 }
 
 impl<T> KeyConditionBuilder<T> for KeyConditionFilled<T> {
-    fn build(self) -> (String, super::AttributeNames, super::AttributeValues) {
-        let (right_str, right_names, right_values) = match self.conjunction {
-            super::key_condition::KeyConditionConjunction::And(s, m, v) => {
-                (format!("AND ({})", s), m, v)
-            }
-        };
-
-        let attr_name = self.attr;
-        let mut left_names: super::AttributeNames = std::collections::HashMap::new();
-        let mut left_values: super::AttributeValues = std::collections::HashMap::new();
-        left_names.insert(format!("#{}", attr_name), attr_name.clone());
-
-        let left_str = match self.cond {
-            super::key_condition::KeyConditionTypes::Eq(placeholder, value) => {
-                left_values.insert(placeholder.clone(), value);
-                format!("#{} = {}", attr_name, placeholder)
-            }
-            super::key_condition::KeyConditionTypes::Gt(placeholder, value) => {
-                left_values.insert(placeholder.clone(), value);
-                format!("#{} > {}", attr_name, placeholder)
-            }
-            super::key_condition::KeyConditionTypes::Ge(placeholder, value) => {
-                left_values.insert(placeholder.clone(), value);
-                format!("#{} >= {}", attr_name, placeholder)
-            }
-            super::key_condition::KeyConditionTypes::Le(placeholder, value) => {
-                left_values.insert(placeholder.clone(), value);
-                format!("#{} <= {}", attr_name, placeholder)
-            }
-            super::key_condition::KeyConditionTypes::Lt(placeholder, value) => {
-                left_values.insert(placeholder.clone(), value);
-                format!("#{} < {}", attr_name, placeholder)
-            }
-            super::key_condition::KeyConditionTypes::Between(
-                placeholder1,
-                value1,
-                placeholder2,
-                value2,
-            ) => {
-                left_values.insert(placeholder1.clone(), value1);
-                left_values.insert(placeholder2.clone(), value2);
-                format!(
-                    "#{} BETWEEN {} AND {}",
-                    attr_name, placeholder1, placeholder2
-                )
-            }
-            super::key_condition::KeyConditionTypes::BeginsWith(placeholder, value) => {
-                left_values.insert(placeholder.clone(), value);
-                format!("begins_with(#{}, {})", attr_name, placeholder)
-            }
-        };
-        (
-            format!("{} {}", left_str, right_str),
-            super::merge_map(left_names, right_names),
-            super::merge_map(left_values, right_values),
-        )
+    fn into_key_cond(self) -> KeyCond {
+        self.cond
     }
 }
 
 impl<T> KeyCondition<T> {
     pub fn eq(self, value: impl super::IntoAttribute) -> KeyConditionFilledOrWaitConjunction<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let cond = super::key_condition::KeyConditionTypes::Eq(placeholder, value.into_attr());
+        let cond = super::key_condition::KeyConditionTypes::Eq(value.into_attr());
         KeyConditionFilledOrWaitConjunction {
             attr: self.attr,
             cond,
@@ -218,8 +162,7 @@ impl<T> KeyCondition<T> {
     }
 
     pub fn gt(self, value: impl super::IntoAttribute) -> KeyConditionFilledOrWaitConjunction<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let cond = super::key_condition::KeyConditionTypes::Gt(placeholder, value.into_attr());
+        let cond = super::key_condition::KeyConditionTypes::Gt(value.into_attr());
         KeyConditionFilledOrWaitConjunction {
             attr: self.attr,
             cond,
@@ -227,8 +170,7 @@ impl<T> KeyCondition<T> {
         }
     }
     pub fn ge(self, value: impl super::IntoAttribute) -> KeyConditionFilledOrWaitConjunction<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let cond = super::key_condition::KeyConditionTypes::Ge(placeholder, value.into_attr());
+        let cond = super::key_condition::KeyConditionTypes::Ge(value.into_attr());
         KeyConditionFilledOrWaitConjunction {
             attr: self.attr,
             cond,
@@ -237,8 +179,7 @@ impl<T> KeyCondition<T> {
     }
 
     pub fn le(self, value: impl super::IntoAttribute) -> KeyConditionFilledOrWaitConjunction<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let cond = super::key_condition::KeyConditionTypes::Le(placeholder, value.into_attr());
+        let cond = super::key_condition::KeyConditionTypes::Le(value.into_attr());
         KeyConditionFilledOrWaitConjunction {
             attr: self.attr,
             cond,
@@ -247,8 +188,7 @@ impl<T> KeyCondition<T> {
     }
 
     pub fn lt(self, value: impl super::IntoAttribute) -> KeyConditionFilledOrWaitConjunction<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let cond = super::key_condition::KeyConditionTypes::Lt(placeholder, value.into_attr());
+        let cond = super::key_condition::KeyConditionTypes::Lt(value.into_attr());
         KeyConditionFilledOrWaitConjunction {
             attr: self.attr,
             cond,
@@ -261,12 +201,8 @@ impl<T> KeyCondition<T> {
         value1: impl super::IntoAttribute,
         value2: impl super::IntoAttribute,
     ) -> KeyConditionFilledOrWaitConjunction<T> {
-        let placeholder1 = format!(":value{}", super::generate_value_id());
-        let placeholder2 = format!(":value{}", super::generate_value_id());
         let cond = super::key_condition::KeyConditionTypes::Between(
-            placeholder1,
             value1.into_attr(),
-            placeholder2,
             value2.into_attr(),
         );
         KeyConditionFilledOrWaitConjunction {
@@ -281,9 +217,7 @@ impl<T> KeyCondition<T> {
         self,
         value: impl super::IntoAttribute,
     ) -> KeyConditionFilledOrWaitConjunction<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let cond =
-            super::key_condition::KeyConditionTypes::BeginsWith(placeholder, value.into_attr());
+        let cond = super::key_condition::KeyConditionTypes::BeginsWith(value.into_attr());
         KeyConditionFilledOrWaitConjunction {
             attr: self.attr,
             cond,
@@ -291,3 +225,203 @@ impl<T> KeyCondition<T> {
         }
     }
 }
+
+// A key condition on a single field, whose operands are bound to the type of the field `V`.
+// e.g. `User::key_cond().id().eq("id0")`
+#[derive(Debug, Clone)]
+pub struct FieldKeyCondition<T, V> {
+    condition: KeyCondition<T>,
+    _value: std::marker::PhantomData<V>,
+}
+
+impl<T, V: super::IntoAttribute> FieldKeyCondition<T, V> {
+    pub fn new<A: super::IntoAttrName>(attr: impl super::IntoAttrPath<A>) -> Self {
+        Self {
+            condition: KeyCondition {
+                attr: attr.into_attr_path().into_document_path(),
+                _token: std::marker::PhantomData,
+            },
+            _value: std::marker::PhantomData,
+        }
+    }
+
+    pub fn eq(self, value: impl Into<V>) -> KeyConditionFilledOrWaitConjunction<T> {
+        self.condition.eq(value.into())
+    }
+
+    pub fn gt(self, value: impl Into<V>) -> KeyConditionFilledOrWaitConjunction<T> {
+        self.condition.gt(value.into())
+    }
+
+    pub fn ge(self, value: impl Into<V>) -> KeyConditionFilledOrWaitConjunction<T> {
+        self.condition.ge(value.into())
+    }
+
+    pub fn le(self, value: impl Into<V>) -> KeyConditionFilledOrWaitConjunction<T> {
+        self.condition.le(value.into())
+    }
+
+    pub fn lt(self, value: impl Into<V>) -> KeyConditionFilledOrWaitConjunction<T> {
+        self.condition.lt(value.into())
+    }
+
+    pub fn between(
+        self,
+        value1: impl Into<V>,
+        value2: impl Into<V>,
+    ) -> KeyConditionFilledOrWaitConjunction<T> {
+        self.condition.between(value1.into(), value2.into())
+    }
+}
+
+impl<T, V: super::IntoAttribute + super::BeginsWithOperand> FieldKeyCondition<T, V> {
+    pub fn begins_with(
+        self,
+        prefix: impl Into<V::Prefix>,
+    ) -> KeyConditionFilledOrWaitConjunction<T> {
+        KeyConditionFilledOrWaitConjunction {
+            attr: self.condition.attr,
+            cond: KeyConditionTypes::BeginsWith(V::prefix_into_attr(prefix.into())),
+            _token: std::marker::PhantomData,
+        }
+    }
+}
+
+// A marker to query sort keys which begin with the value, e.g. `.sort_key(Prefix("2021-"))`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prefix<V>(pub V);
+
+/// Converts a Rust range into the condition on a sort key of type `V`.
+/// e.g. `a..=b` is `BETWEEN a AND b`, `..b` is `< b` and `a..` is `>= a`.
+/// Returns `None` for an empty range, e.g. `3..3` or `5..=3`,
+/// since DynamoDB rejects `BETWEEN` whose lower bound is above its upper bound.
+///
+/// `a..b` is only implemented for integers, which have the value just before `b`.
+/// Use `a..=b` for strings, binaries and times.
+///
+/// ```
+/// use raiden::IntoSortKeyRange;
+/// assert!(IntoSortKeyRange::<usize>::into_sort_key_range(1usize..3).is_some());
+/// assert!(IntoSortKeyRange::<String>::into_sort_key_range("a".to_owned()..="b".to_owned()).is_some());
+/// ```
+///
+/// ```compile_fail
+/// use raiden::IntoSortKeyRange;
+/// IntoSortKeyRange::<String>::into_sort_key_range("a".to_owned().."b".to_owned());
+/// ```
+pub trait IntoSortKeyRange<V> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes>;
+}
+
+// Sort key types which have the value just before a value, so that `a..b` can be queried
+// as `BETWEEN a AND pred(b)` since `BETWEEN` is inclusive and a sort key takes only one condition.
+pub trait SortKeyPredecessor: Sized {
+    fn predecessor(&self) -> Option<Self>;
+}
+
+macro_rules! sort_key_predecessor_for_int {
+    ($to: ty) => {
+        impl SortKeyPredecessor for $to {
+            fn predecessor(&self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+        }
+    };
+}
+
+sort_key_predecessor_for_int!(usize);
+sort_key_predecessor_for_int!(u128);
+sort_key_predecessor_for_int!(u64);
+sort_key_predecessor_for_int!(u32);
+sort_key_predecessor_for_int!(u16);
+sort_key_predecessor_for_int!(u8);
+
+sort_key_predecessor_for_int!(isize);
+sort_key_predecessor_for_int!(i128);
+sort_key_predecessor_for_int!(i64);
+sort_key_predecessor_for_int!(i32);
+sort_key_predecessor_for_int!(i16);
+sort_key_predecessor_for_int!(i8);
+
+impl<V: super::IntoAttribute + SortKeyPredecessor + PartialOrd> IntoSortKeyRange<V>
+    for std::ops::Range<V>
+{
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        if self.start.partial_cmp(&self.end) != Some(std::cmp::Ordering::Less) {
+            return None;
+        }
+        let last = self.end.predecessor()?;
+        Some(KeyConditionTypes::Between(
+            self.start.into_attr(),
+            last.into_attr(),
+        ))
+    }
+}
+
+impl<V: super::IntoAttribute + PartialOrd> IntoSortKeyRange<V> for std::ops::RangeInclusive<V> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        let (start, end) = self.into_inner();
+        if matches!(
+            start.partial_cmp(&end),
+            None | Some(std::cmp::Ordering::Greater)
+        ) {
+            return None;
+        }
+        Some(KeyConditionTypes::Between(
+            start.into_attr(),
+            end.into_attr(),
+        ))
+    }
+}
+
+impl<V: super::IntoAttribute> IntoSortKeyRange<V> for std::ops::RangeFrom<V> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        Some(KeyConditionTypes::Ge(self.start.into_attr()))
+    }
+}
+
+impl<V: super::IntoAttribute> IntoSortKeyRange<V> for std::ops::RangeTo<V> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        Some(KeyConditionTypes::Lt(self.end.into_attr()))
+    }
+}
+
+impl<V: super::IntoAttribute> IntoSortKeyRange<V> for std::ops::RangeToInclusive<V> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        Some(KeyConditionTypes::Le(self.end.into_attr()))
+    }
+}
+
+// `"a"..="b"` for a `String` sort key.
+impl IntoSortKeyRange<String> for std::ops::RangeInclusive<&str> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        let (start, end) = self.into_inner();
+        (start.to_owned()..=end.to_owned()).into_sort_key_range()
+    }
+}
+
+impl IntoSortKeyRange<String> for std::ops::RangeFrom<&str> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        (self.start.to_owned()..).into_sort_key_range()
+    }
+}
+
+impl IntoSortKeyRange<String> for std::ops::RangeTo<&str> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        (..self.end.to_owned()).into_sort_key_range()
+    }
+}
+
+impl IntoSortKeyRange<String> for std::ops::RangeToInclusive<&str> {
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        (..=self.end.to_owned()).into_sort_key_range()
+    }
+}
+
+impl<V: super::IntoAttribute + super::BeginsWithOperand, A: Into<V>> IntoSortKeyRange<V>
+    for Prefix<A>
+{
+    fn into_sort_key_range(self) -> Option<KeyConditionTypes> {
+        Some(KeyConditionTypes::BeginsWith(self.0.into().into_attr()))
+    }
+}
@@ -1,22 +1,34 @@
 #[macro_use]
 extern crate serde_derive;
+// The derived code refers to `::raiden`, so let the unit tests of derived types resolve it.
+#[cfg(test)]
+extern crate self as raiden;
 
+pub mod binary;
 pub mod condition;
+pub mod datetime;
 pub mod errors;
+pub mod expression;
 pub mod id_generator;
+#[cfg(feature = "json")]
+mod json;
 pub mod key_condition;
 pub mod next_token;
 pub mod ops;
+pub mod path;
 pub mod retry;
 pub mod types;
 pub mod update_expression;
 pub mod value_id;
 
+pub use binary::*;
 pub use condition::*;
+pub use datetime::*;
 pub use errors::*;
 pub use key_condition::*;
 pub use next_token::*;
 pub use ops::*;
+pub use path::*;
 pub use retry::*;
 
 pub use id_generator::*;
@@ -87,14 +99,26 @@ pub trait ToAttrMaps: Sized {
     fn to_attr_maps(&self) -> (AttributeNames, AttributeValues);
 }
 
+pub trait FromItem: Sized {
+    fn from_item(item: &AttributeValues) -> Result<Self, RaidenError>;
+}
+
 pub trait IntoAttribute: Sized {
     fn into_attr(self) -> AttributeValue;
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConversionError {
     ValueIsNone,
     ParseInt,
+    // A field of a map attribute, e.g. of a `RaidenAttribute` struct, can not be converted, with the error of the field.
+    Field(String, Box<ConversionError>),
+    // The tag of an enum attribute, e.g. of a `RaidenAttribute` enum, is none of its variants.
+    UnknownVariant(String, String),
+    // A time value is not valid or out of the range of the type.
+    ParseTime(String),
+    // A number value is not valid for the type, e.g. a fraction or out of range, with the type name.
+    ParseNumber(String, String),
 }
 
 impl std::fmt::Display for ConversionError {
@@ -102,6 +126,16 @@ impl std::fmt::Display for ConversionError {
         match self {
             ConversionError::ValueIsNone => write!(f, "Value is none"),
             ConversionError::ParseInt => write!(f, "Parsing error of integer"),
+            ConversionError::Field(name, e) => {
+                write!(f, "Conversion error of field {:?}: {}", name, e)
+            }
+            ConversionError::UnknownVariant(enum_name, variant) => {
+                write!(f, "Unknown variant {:?} of enum {}", variant, enum_name)
+            }
+            ConversionError::ParseTime(value) => write!(f, "Parsing error of time {:?}", value),
+            ConversionError::ParseNumber(value, ty) => {
+                write!(f, "Parsing error of number {:?} into {}", value, ty)
+            }
         }
     }
 }
@@ -116,6 +150,82 @@ pub trait FromStringSetItem: Sized {
     fn from_ss_item(value: String) -> Result<Self, ConversionError>;
 }
 
+// Field types which typed builders accept `begins_with` for, with the type of the prefix.
+pub trait BeginsWithOperand {
+    type Prefix;
+
+    fn prefix_into_attr(prefix: Self::Prefix) -> AttributeValue;
+}
+
+// Field types which typed builders accept `contains` for, with the type of the operand.
+pub trait ContainsOperand {
+    type Operand;
+
+    fn operand_into_attr(operand: Self::Operand) -> AttributeValue;
+}
+
+// Field types which typed builders accept the `ADD` action for, i.e. numbers and sets.
+pub trait AddOperand {}
+
+// Field types which typed builders accept the `DELETE` action for, i.e. sets.
+pub trait DeleteOperand {}
+
+impl BeginsWithOperand for String {
+    type Prefix = String;
+
+    fn prefix_into_attr(prefix: Self::Prefix) -> AttributeValue {
+        prefix.into_attr()
+    }
+}
+
+impl ContainsOperand for String {
+    type Operand = String;
+
+    fn operand_into_attr(operand: Self::Operand) -> AttributeValue {
+        operand.into_attr()
+    }
+}
+
+impl<A: IntoAttribute> ContainsOperand for Vec<A> {
+    type Operand = A;
+
+    fn operand_into_attr(operand: Self::Operand) -> AttributeValue {
+        operand.into_attr()
+    }
+}
+
+impl<A: std::hash::Hash + IntoStringSetItem> ContainsOperand for std::collections::HashSet<A> {
+    type Operand = A;
+
+    fn operand_into_attr(operand: Self::Operand) -> AttributeValue {
+        operand.into_ss_item().into_attr()
+    }
+}
+
+impl<A: std::hash::Hash + IntoStringSetItem> AddOperand for std::collections::HashSet<A> {}
+
+impl<A: std::hash::Hash + IntoStringSetItem> DeleteOperand for std::collections::HashSet<A> {}
+
+impl<T: BeginsWithOperand> BeginsWithOperand for Option<T> {
+    type Prefix = T::Prefix;
+
+    fn prefix_into_attr(prefix: Self::Prefix) -> AttributeValue {
+        T::prefix_into_attr(prefix)
+    }
+}
+
+impl<T: ContainsOperand> ContainsOperand for Option<T> {
+    type Operand = T::Operand;
+
+    fn operand_into_attr(operand: Self::Operand) -> AttributeValue {
+        T::operand_into_attr(operand)
+    }
+}
+
+impl<T: AddOperand> AddOperand for Option<T> {}
+
+impl<T: DeleteOperand> DeleteOperand for Option<T> {}
+
 impl IntoAttribute for String {
     fn into_attr(self) -> AttributeValue {
         // Empty String is allowed since 2020/5
@@ -194,6 +304,13 @@ impl<'a> FromAttribute for std::borrow::Cow<'a, str> {
     }
 }
 
+// Parses `N` or an item of `NS` without panicking, since DynamoDB can hold
+// numbers which do not fit the field type, e.g. `1.5` or `256` for `u8`.
+pub(crate) fn parse_number<T: std::str::FromStr>(n: String) -> Result<T, ConversionError> {
+    n.parse()
+        .map_err(|_| ConversionError::ParseNumber(n, std::any::type_name::<T>().to_owned()))
+}
+
 macro_rules! default_attr_for_num {
     ($to: ty) => {
         impl IntoAttribute for $to {
@@ -212,20 +329,25 @@ macro_rules! default_attr_for_num {
                 value
                     .unwrap()
                     .n
-                    .map(|v| v.parse().unwrap())
                     .ok_or(ConversionError::ValueIsNone)
+                    .and_then(parse_number)
             }
         }
+        impl AddOperand for $to {}
     };
 }
 
+// `N` holds up to 38 significant digits, so `i128` and `u128` values of 39 digits,
+// e.g. `i128::MIN` or `u128::MAX`, are rejected by DynamoDB on write.
 default_attr_for_num!(usize);
+default_attr_for_num!(u128);
 default_attr_for_num!(u64);
 default_attr_for_num!(u32);
 default_attr_for_num!(u16);
 default_attr_for_num!(u8);
 
 default_attr_for_num!(isize);
+default_attr_for_num!(i128);
 default_attr_for_num!(i64);
 default_attr_for_num!(i32);
 default_attr_for_num!(i16);
@@ -234,6 +356,12 @@ default_attr_for_num!(i8);
 default_attr_for_num!(f32);
 default_attr_for_num!(f64);
 
+// `N` holds up to 38 digits, so use decimals rather than `f64` e.g. for money amounts.
+#[cfg(feature = "rust_decimal")]
+default_attr_for_num!(rust_decimal::Decimal);
+#[cfg(feature = "bigdecimal")]
+default_attr_for_num!(bigdecimal::BigDecimal);
+
 impl<T: IntoAttribute> IntoAttribute for Option<T> {
     fn into_attr(self) -> AttributeValue {
         match self {
@@ -351,28 +479,45 @@ macro_rules! default_number_set_convertor {
                     return Ok(std::collections::HashSet::new());
                 }
                 let mut nums = value.ns.ok_or(ConversionError::ValueIsNone)?;
-                let mut results: Vec<Result<$to, ConversionError>> = nums
-                    .drain(..)
-                    .map(|ns| ns.parse().map_err(|_| ConversionError::ParseInt))
-                    .collect();
+                let mut results: Vec<Result<$to, ConversionError>> =
+                    nums.drain(..).map(parse_number).collect();
                 results.drain(..).collect()
             }
         }
+
+        impl AddOperand for std::collections::HashSet<$to> {}
+
+        impl ContainsOperand for std::collections::HashSet<$to> {
+            type Operand = $to;
+
+            fn operand_into_attr(operand: Self::Operand) -> AttributeValue {
+                operand.into_attr()
+            }
+        }
+
+        impl DeleteOperand for std::collections::HashSet<$to> {}
     };
 }
 
 default_number_set_convertor!(usize);
+default_number_set_convertor!(u128);
 default_number_set_convertor!(u64);
 default_number_set_convertor!(u32);
 default_number_set_convertor!(u16);
 default_number_set_convertor!(u8);
 
 default_number_set_convertor!(isize);
+default_number_set_convertor!(i128);
 default_number_set_convertor!(i64);
 default_number_set_convertor!(i32);
 default_number_set_convertor!(i16);
 default_number_set_convertor!(i8);
 
+#[cfg(feature = "rust_decimal")]
+default_number_set_convertor!(rust_decimal::Decimal);
+#[cfg(feature = "bigdecimal")]
+default_number_set_convertor!(bigdecimal::BigDecimal);
+
 impl<A: std::hash::Hash + IntoStringSetItem> IntoAttribute for std::collections::HashSet<A> {
     fn into_attr(self) -> AttributeValue {
         if self.is_empty() {
@@ -404,6 +549,51 @@ impl<A: std::hash::Hash + std::cmp::Eq + FromStringSetItem> FromAttribute
     }
 }
 
+macro_rules! default_map_convertor {
+    ($map: ident) => {
+        impl<A: IntoAttribute> IntoAttribute for std::collections::$map<String, A> {
+            fn into_attr(self) -> AttributeValue {
+                if self.is_empty() {
+                    // See. https://github.com/raiden-rs/raiden/issues/57
+                    return AttributeValue {
+                        null: Some(true),
+                        ..Default::default()
+                    };
+                }
+                AttributeValue {
+                    m: Some(self.into_iter().map(|(k, v)| (k, v.into_attr())).collect()),
+                    ..AttributeValue::default()
+                }
+            }
+        }
+
+        impl<A: FromAttribute> FromAttribute for std::collections::$map<String, A> {
+            fn from_attr(value: Option<AttributeValue>) -> Result<Self, ConversionError> {
+                if value.is_none() {
+                    return Ok(std::collections::$map::new());
+                }
+                let value = value.unwrap();
+                if let Some(true) = value.null {
+                    // See. https://github.com/raiden-rs/raiden/issues/57
+                    return Ok(std::collections::$map::new());
+                }
+                value
+                    .m
+                    .ok_or(ConversionError::ValueIsNone)?
+                    .into_iter()
+                    .map(|(k, v)| match A::from_attr(Some(v)) {
+                        Ok(v) => Ok((k, v)),
+                        Err(e) => Err(ConversionError::Field(k, Box::new(e))),
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+default_map_convertor!(HashMap);
+default_map_convertor!(BTreeMap);
+
 pub struct GetItemController<'a> {
     pub client: &'a DynamoDbClient,
     pub item: GetItemInput,
@@ -419,3 +609,270 @@ pub fn merge_map<T>(
 pub fn is_attr_value_empty(a: &AttributeValue) -> bool {
     a == &AttributeValue::default()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_number_into_attr_and_back() {
+        // The widest values which DynamoDB stores, 38 significant digits.
+        let min: i128 = -99_999_999_999_999_999_999_999_999_999_999_999_999;
+        let value = min.into_attr();
+        assert_eq!(
+            value.n,
+            Some("-99999999999999999999999999999999999999".to_owned())
+        );
+        assert_eq!(i128::from_attr(Some(value)).unwrap(), min);
+        let max: u128 = 99_999_999_999_999_999_999_999_999_999_999_999_999;
+        assert_eq!(u128::from_attr(Some(max.into_attr())).unwrap(), max);
+
+        let err = i64::from_attr(Some(1.5.into_attr())).unwrap_err();
+        assert_eq!(err.to_string(), "Parsing error of number \"1.5\" into i64");
+        assert!(matches!(
+            u8::from_attr(Some(256u16.into_attr())),
+            Err(ConversionError::ParseNumber(v, t)) if v == "256" && t == "u8"
+        ));
+        assert!(matches!(
+            Option::<u8>::from_attr(Some((-1).into_attr())),
+            Err(ConversionError::ParseNumber(_, _))
+        ));
+
+        let value = AttributeValue {
+            ns: Some(vec!["1".to_owned(), "1.5".to_owned()]),
+            ..AttributeValue::default()
+        };
+        assert!(matches!(
+            std::collections::HashSet::<i32>::from_attr(Some(value)),
+            Err(ConversionError::ParseNumber(v, _)) if v == "1.5"
+        ));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal_into_attr_and_back() {
+        let amount: rust_decimal::Decimal = "0.10".parse().unwrap();
+        let value = amount.into_attr();
+        assert_eq!(value.n, Some("0.10".to_owned()));
+        assert_eq!(
+            rust_decimal::Decimal::from_attr(Some(value)).unwrap(),
+            amount
+        );
+
+        // 38 digits do not fit in `Decimal`.
+        let value = 12_345_678_901_234_567_890_123_456_789_012_345_678i128.into_attr();
+        assert!(matches!(
+            rust_decimal::Decimal::from_attr(Some(value)),
+            Err(ConversionError::ParseNumber(_, _))
+        ));
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn test_big_decimal_into_attr_and_back() {
+        let amount: bigdecimal::BigDecimal =
+            "12345678901234567890.123456789012345678".parse().unwrap();
+        let value = amount.clone().into_attr();
+        assert_eq!(
+            value.n,
+            Some("12345678901234567890.123456789012345678".to_owned())
+        );
+        assert_eq!(
+            bigdecimal::BigDecimal::from_attr(Some(value)).unwrap(),
+            amount
+        );
+
+        let mut amounts = std::collections::HashSet::new();
+        amounts.insert(amount);
+        let value = amounts.clone().into_attr();
+        assert_eq!(
+            std::collections::HashSet::<bigdecimal::BigDecimal>::from_attr(Some(value)).unwrap(),
+            amounts
+        );
+    }
+
+    #[test]
+    fn test_map_into_attr_and_back() {
+        let mut labels = std::collections::BTreeMap::new();
+        labels.insert("env".to_owned(), "prod".to_owned());
+        let value = labels.clone().into_attr();
+        assert_eq!(value.m.as_ref().unwrap()["env"].s, Some("prod".to_owned()));
+        assert_eq!(
+            std::collections::BTreeMap::<String, String>::from_attr(Some(value)).unwrap(),
+            labels
+        );
+
+        let empty: std::collections::HashMap<String, u32> = std::collections::HashMap::new();
+        assert_eq!(empty.into_attr().null, Some(true));
+
+        let mut m: AttributeValues = std::collections::HashMap::new();
+        m.insert("alice".to_owned(), "ten".into_attr());
+        let value = AttributeValue {
+            m: Some(m),
+            ..AttributeValue::default()
+        };
+        assert!(matches!(
+            std::collections::HashMap::<String, u32>::from_attr(Some(value)),
+            Err(ConversionError::Field(k, e)) if k == "alice" && *e == ConversionError::ValueIsNone
+        ));
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    #[raiden(rename_all = "camelCase")]
+    pub struct Settings {
+        dark_mode: bool,
+        #[raiden(use_default)]
+        font_size: usize,
+        #[raiden(rename = "lang")]
+        language: Option<String>,
+    }
+
+    #[test]
+    fn test_nested_struct_into_attr_and_back() {
+        let settings = Settings {
+            dark_mode: false,
+            font_size: 12,
+            language: None,
+        };
+        let value = settings.clone().into_attr();
+        let m = value.m.clone().unwrap();
+        assert_eq!(m["darkMode"].bool, Some(false));
+        assert_eq!(m["fontSize"].n, Some("12".to_owned()));
+        assert_eq!(m["lang"].null, Some(true));
+        assert_eq!(Settings::from_attr(Some(value)).unwrap(), settings);
+
+        let mut m = m;
+        m.remove("darkMode");
+        let value = AttributeValue {
+            m: Some(m),
+            ..AttributeValue::default()
+        };
+        assert!(Settings::from_attr(Some(value)).is_err());
+        assert!(Settings::from_attr(Some("not a map".into_attr())).is_err());
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    #[raiden(rename_all = "snake_case")]
+    pub enum Status {
+        Todo,
+        InProgress,
+        #[raiden(rename = "finished")]
+        Done,
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    #[raiden(tag = "kind")]
+    #[raiden(content = "data")]
+    pub enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rect { width: f64, height: f64 },
+    }
+
+    #[test]
+    fn test_enum_into_attr_and_back() {
+        assert_eq!(Status::Todo.into_attr().s, Some("todo".to_owned()));
+        assert_eq!(Status::Done.into_attr().s, Some("finished".to_owned()));
+        assert_eq!(
+            Status::from_attr(Some("in_progress".into_attr())).unwrap(),
+            Status::InProgress
+        );
+
+        let shape = Shape::Rect {
+            width: 2.0,
+            height: 3.0,
+        };
+        let value = shape.clone().into_attr();
+        let m = value.m.clone().unwrap();
+        assert_eq!(m["kind"].s, Some("Rect".to_owned()));
+        assert_eq!(
+            m["data"].m.as_ref().unwrap()["width"].n,
+            Some("2".to_owned())
+        );
+        assert_eq!(Shape::from_attr(Some(value)).unwrap(), shape);
+        assert!(!Shape::Point.into_attr().m.unwrap().contains_key("data"));
+        for shape in [Shape::Point, Shape::Circle(1.5), Shape::Line(1.0, 2.0)] {
+            assert_eq!(
+                Shape::from_attr(Some(shape.clone().into_attr())).unwrap(),
+                shape
+            );
+        }
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    pub struct Drawing {
+        shape: Shape,
+    }
+
+    #[test]
+    fn test_enum_unknown_variant() {
+        let err = Status::from_attr(Some("archived".into_attr())).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown variant \"archived\" of enum Status"
+        );
+
+        let mut m: AttributeValues = std::collections::HashMap::new();
+        m.insert("kind".to_owned(), "Triangle".into_attr());
+        let value = AttributeValue {
+            m: Some(m),
+            ..AttributeValue::default()
+        };
+        assert!(matches!(
+            Shape::from_attr(Some(value)),
+            Err(ConversionError::UnknownVariant(e, v)) if e == "Shape" && v == "Triangle"
+        ));
+
+        let mut m: AttributeValues = std::collections::HashMap::new();
+        m.insert("kind".to_owned(), "Circle".into_attr());
+        m.insert("data".to_owned(), "big".into_attr());
+        let value = AttributeValue {
+            m: Some(m),
+            ..AttributeValue::default()
+        };
+        assert!(Shape::from_attr(Some(value)).is_err());
+
+        // An unknown variant in a field is kept in the error of the field.
+        let mut shape: AttributeValues = std::collections::HashMap::new();
+        shape.insert("kind".to_owned(), "Triangle".into_attr());
+        let mut m: AttributeValues = std::collections::HashMap::new();
+        m.insert(
+            "shape".to_owned(),
+            AttributeValue {
+                m: Some(shape),
+                ..AttributeValue::default()
+            },
+        );
+        let value = AttributeValue {
+            m: Some(m),
+            ..AttributeValue::default()
+        };
+        let err = Drawing::from_attr(Some(value)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Conversion error of field \"shape\": Unknown variant \"Triangle\" of enum Shape"
+        );
+
+        let mut m: AttributeValues = std::collections::HashMap::new();
+        m.insert("kind".to_owned(), "Line".into_attr());
+        m.insert(
+            "data".to_owned(),
+            AttributeValue {
+                l: Some(vec![1.0.into_attr(), "x".into_attr()]),
+                ..AttributeValue::default()
+            },
+        );
+        let value = AttributeValue {
+            m: Some(m),
+            ..AttributeValue::default()
+        };
+        assert_eq!(
+            Shape::from_attr(Some(value)).unwrap_err(),
+            ConversionError::Field("1".to_owned(), Box::new(ConversionError::ValueIsNone))
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+// See. https://github.com/rusoto/rusoto/blob/69e7c9150d98916ef8fc814f5cd17eb0e4dee3d3/rusoto/services/dynamodb/src/generated.rs#L450
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BatchWriteOutput {
+    pub consumed_capacity: Option<Vec<crate::ConsumedCapacity>>,
+    pub unprocessed_items: Vec<crate::WriteRequest>,
+}
+
+// Adds `capacity` of a chunk to `total`, summing up the units of the same table.
+pub fn merge_consumed_capacity(
+    total: &mut Option<Vec<crate::ConsumedCapacity>>,
+    capacity: Vec<crate::ConsumedCapacity>,
+) {
+    fn add(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+        match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+        }
+    }
+
+    let total = total.get_or_insert_with(Vec::new);
+    for capacity in capacity.into_iter() {
+        match total
+            .iter_mut()
+            .find(|c| c.table_name == capacity.table_name)
+        {
+            Some(c) => {
+                c.capacity_units = add(c.capacity_units, capacity.capacity_units);
+                c.read_capacity_units = add(c.read_capacity_units, capacity.read_capacity_units);
+                c.write_capacity_units = add(c.write_capacity_units, capacity.write_capacity_units);
+            }
+            None => total.push(capacity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capacity(table_name: &str, units: f64) -> crate::ConsumedCapacity {
+        crate::ConsumedCapacity {
+            table_name: Some(table_name.to_owned()),
+            capacity_units: Some(units),
+            ..crate::ConsumedCapacity::default()
+        }
+    }
+
+    #[test]
+    fn test_merge_consumed_capacity() {
+        let mut total = None;
+        merge_consumed_capacity(&mut total, vec![capacity("a", 25.0)]);
+        merge_consumed_capacity(&mut total, vec![capacity("a", 10.0), capacity("b", 1.0)]);
+        assert_eq!(total, Some(vec![capacity("a", 35.0), capacity("b", 1.0)]));
+    }
+}
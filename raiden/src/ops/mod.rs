@@ -1,10 +1,14 @@
 pub mod batch_get;
+pub mod batch_write;
 pub mod get;
+pub mod page;
 pub mod put;
 pub mod update;
 pub mod query;
 pub mod scan;
 
+pub mod transact_get;
 pub mod transact_write;
 
+pub use transact_get::*;
 pub use transact_write::*;
@@ -0,0 +1,91 @@
+use futures::stream::{self, TryStreamExt};
+
+pub use futures::stream::Stream;
+
+// Drives a paginated request lazily.
+// `fetch` sends a request for a page and returns it with the input for the next page,
+// or `None` when there is no more page.
+pub fn paginate<'a, I, P, F, Fut>(
+    input: Result<I, crate::RaidenError>,
+    fetch: F,
+) -> impl Stream<Item = Result<P, crate::RaidenError>> + 'a
+where
+    I: 'a,
+    P: 'a,
+    F: FnMut(I) -> Fut + 'a,
+    Fut: std::future::Future<Output = Result<(P, Option<I>), crate::RaidenError>> + 'a,
+{
+    stream::unfold(Some((input, fetch)), |state| async move {
+        let (input, mut fetch) = state?;
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => return Some((Err(e), None)),
+        };
+        match fetch(input).await {
+            Ok((page, Some(next))) => Some((Ok(page), Some((Ok(next), fetch)))),
+            Ok((page, None)) => Some((Ok(page), None)),
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
+// Flattens a stream of pages into a stream of their items.
+pub fn flatten_pages<'a, P, T, S>(
+    pages: S,
+    into_items: fn(P) -> Vec<T>,
+) -> impl Stream<Item = Result<T, crate::RaidenError>> + 'a
+where
+    P: 'a,
+    T: 'a,
+    S: Stream<Item = Result<P, crate::RaidenError>> + 'a,
+{
+    pages
+        .map_ok(move |page| stream::iter(into_items(page).into_iter().map(Ok)))
+        .try_flatten()
+}
+
+// Requests every page in order and folds them into `init`.
+pub async fn fold_pages<P, S>(
+    pages: S,
+    init: P,
+    merge: fn(P, P) -> P,
+) -> Result<P, crate::RaidenError>
+where
+    S: Stream<Item = Result<P, crate::RaidenError>>,
+{
+    pages
+        .try_fold(init, |acc, page| futures::future::ok(merge(acc, page)))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginate_until_no_next_input() {
+        let pages = paginate(Ok(0), |n: usize| async move {
+            let next = if n < 2 { Some(n + 1) } else { None };
+            Ok((vec![n * 10, n * 10 + 1], next))
+        });
+        let items: Vec<usize> =
+            futures::executor::block_on(flatten_pages(pages, |page| page).try_collect()).unwrap();
+        assert_eq!(items, vec![0, 1, 10, 11, 20, 21]);
+    }
+
+    #[test]
+    fn test_paginate_stops_after_error() {
+        let pages = paginate(Ok(0), |n: usize| async move {
+            if n == 1 {
+                return Err(crate::RaidenError::NextTokenDecodeError);
+            }
+            Ok((n, Some(n + 1)))
+        });
+        let pages: Vec<Result<usize, crate::RaidenError>> =
+            futures::executor::block_on(futures::StreamExt::collect(pages));
+        assert_eq!(
+            pages,
+            vec![Ok(0), Err(crate::RaidenError::NextTokenDecodeError)]
+        );
+    }
+}
@@ -9,3 +9,104 @@ pub struct ScanOutput<T> {
     pub last_evaluated_key: Option<::std::collections::HashMap<String, crate::AttributeValue>>,
     pub scanned_count: Option<i64>,
 }
+
+// Segments of a parallel scan which run at the same time, unless `concurrency` is set.
+pub const DEFAULT_SCAN_CONCURRENCY: usize = 16;
+
+// Runs the segments of a parallel scan, at most `concurrency` at a time, and merges them into a single output.
+// Fails with the first error returned from any segment.
+pub async fn merge_segments<T, F>(
+    segments: impl IntoIterator<Item = F>,
+    concurrency: usize,
+) -> Result<ScanOutput<T>, crate::RaidenError>
+where
+    F: std::future::Future<Output = Result<ScanOutput<T>, crate::RaidenError>>,
+{
+    use futures::{StreamExt, TryStreamExt};
+
+    // `buffer_unordered(0)` would not limit the segments at all.
+    let mut outputs = futures::stream::iter(segments).buffer_unordered(concurrency.max(1));
+    let mut merged = ScanOutput {
+        consumed_capacity: None,
+        items: vec![],
+        count: Some(0),
+        last_evaluated_key: None,
+        scanned_count: Some(0),
+    };
+    while let Some(output) = outputs.try_next().await? {
+        merged.items.extend(output.items);
+        merged.count = Some(merged.count.unwrap_or(0) + output.count.unwrap_or(0));
+        merged.scanned_count =
+            Some(merged.scanned_count.unwrap_or(0) + output.scanned_count.unwrap_or(0));
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Returns `Pending` once, so that other segments are polled in the meantime.
+    struct YieldNow(bool);
+
+    impl std::future::Future for YieldNow {
+        type Output = ();
+
+        fn poll(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<()> {
+            if self.0 {
+                return std::task::Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+
+    #[test]
+    fn test_merge_segments_with_concurrency() {
+        let running = AtomicUsize::new(0);
+        let max_running = AtomicUsize::new(0);
+        let segments = (0..10).map(|segment| {
+            let running = &running;
+            let max_running = &max_running;
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now, Ordering::SeqCst);
+                YieldNow(false).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(ScanOutput {
+                    consumed_capacity: None,
+                    items: vec![segment],
+                    count: Some(1),
+                    last_evaluated_key: None,
+                    scanned_count: Some(2),
+                })
+            }
+        });
+        let merged = futures::executor::block_on(merge_segments(segments, 3)).unwrap();
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+        let mut items = merged.items;
+        items.sort_unstable();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert_eq!(merged.count, Some(10));
+        assert_eq!(merged.scanned_count, Some(20));
+    }
+
+    #[test]
+    fn test_merge_segments_stops_after_error() {
+        let segments = (0..3).map(|segment| async move {
+            if segment == 1 {
+                return Err(crate::RaidenError::NextTokenDecodeError);
+            }
+            Ok(ScanOutput::<usize>::default())
+        });
+        assert_eq!(
+            futures::executor::block_on(merge_segments(segments, 1)).unwrap_err(),
+            crate::RaidenError::NextTokenDecodeError
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{DynamoDb, TransactGetItem};
+
+pub struct ReadTx {
+    items: Vec<crate::TransactGetItem>,
+    client: crate::DynamoDbClient,
+    retry_condition: crate::RetryCondition,
+}
+
+impl ReadTx {
+    pub fn new(region: crate::Region) -> Self {
+        let client = crate::DynamoDbClient::new(region);
+        Self {
+            items: vec![],
+            client,
+            retry_condition: crate::RetryCondition::new(),
+        }
+    }
+
+    pub fn with_retries(mut self, s: Box<dyn crate::retry::RetryStrategy + Send + Sync>) -> Self {
+        self.retry_condition.strategy = s;
+        self
+    }
+
+    pub fn get(mut self, builder: impl TransactGetBuilder) -> Self {
+        self.items.push(TransactGetItem {
+            get: builder.build(),
+        });
+        self
+    }
+
+    pub async fn run(self) -> Result<TransactGetOutput, crate::RaidenError> {
+        let policy: crate::RetryPolicy = self.retry_condition.strategy.policy().into();
+        let client = self.client;
+        let input = crate::TransactGetItemsInput {
+            return_consumed_capacity: None,
+            transact_items: self.items,
+        };
+        policy
+            .retry_if(
+                move || {
+                    let client = client.clone();
+                    let input = input.clone();
+                    async { ReadTx::inner_run(client, input).await }
+                },
+                &self.retry_condition,
+            )
+            .await
+    }
+
+    async fn inner_run(
+        client: crate::DynamoDbClient,
+        input: crate::TransactGetItemsInput,
+    ) -> Result<TransactGetOutput, crate::RaidenError> {
+        let res = client.transact_get_items(input).await?;
+        let items = res
+            .responses
+            .unwrap_or_default()
+            .into_iter()
+            .map(|r| r.item)
+            .collect();
+        Ok(TransactGetOutput {
+            consumed_capacity: res.consumed_capacity,
+            items,
+        })
+    }
+}
+
+// See. https://github.com/rusoto/rusoto/blob/69e7c9150d98916ef8fc814f5cd17eb0e4dee3d3/rusoto/services/dynamodb/src/generated.rs#L4000
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TransactGetOutput {
+    pub consumed_capacity: Option<Vec<crate::ConsumedCapacity>>,
+    // Same order as the `get` calls. `None` means the item was not found.
+    pub items: Vec<Option<crate::AttributeValues>>,
+}
+
+impl TransactGetOutput {
+    /// Converts the `index`-th item of the transaction into `T`.
+    /// Returns `Ok(None)` when the item is not found,
+    /// and `Err(RaidenError::Validation)` when `index` is out of the transaction.
+    pub fn get<T: crate::FromItem>(&self, index: usize) -> Result<Option<T>, crate::RaidenError> {
+        match self.items.get(index) {
+            Some(Some(item)) => Ok(Some(T::from_item(item)?)),
+            Some(None) => Ok(None),
+            None => Err(crate::RaidenError::Validation(format!(
+                "index {} is out of the transaction of {} items",
+                index,
+                self.items.len()
+            ))),
+        }
+    }
+}
+
+pub trait TransactGetBuilder {
+    fn build(self) -> crate::Get;
+}
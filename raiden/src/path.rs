@@ -0,0 +1,197 @@
+// See. https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.Attributes.html#Expressions.Attributes.NestedElements.DocumentPathExamples
+use super::{AttributeNames, IntoAttrName, ToAttrNames};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathElement {
+    Field(String),
+    Index(usize),
+}
+
+// A path to an attribute, which may point into a map or a list.
+// e.g. `address.city` is rendered as `#address.#city` and `tags[2]` as `#tags[2]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentPath {
+    pub root: String,
+    pub elements: Vec<PathElement>,
+}
+
+impl DocumentPath {
+    pub fn new(root: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            elements: vec![],
+        }
+    }
+
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.elements.push(PathElement::Field(name.into()));
+        self
+    }
+
+    pub fn index(mut self, index: usize) -> Self {
+        self.elements.push(PathElement::Index(index));
+        self
+    }
+}
+
+// The placeholder of an attribute name, e.g. `#address` for `address`.
+// A placeholder can contain only alphanumerics and `_`, so any other name is escaped byte by byte
+// after `#_`, with `_` as `__` and other characters as `_` and the hex code, e.g. `#_user_2did` for `user-id`.
+// Names used as they are never start with `_`, so they can not collide with escaped ones.
+fn placeholder(name: &str) -> String {
+    let mut bytes = name.bytes();
+    if matches!(bytes.next(), Some(b) if b.is_ascii_alphanumeric())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+    {
+        return format!("#{}", name);
+    }
+    name.bytes().fold("#_".to_owned(), |mut placeholder, b| {
+        if b.is_ascii_alphanumeric() {
+            placeholder.push(b as char);
+        } else if b == b'_' {
+            placeholder.push_str("__");
+        } else {
+            placeholder.push_str(&format!("_{:02x}", b));
+        }
+        placeholder
+    })
+}
+
+impl std::fmt::Display for DocumentPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", placeholder(&self.root))?;
+        for element in self.elements.iter() {
+            match element {
+                PathElement::Field(name) => write!(f, ".{}", placeholder(name))?,
+                PathElement::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ToAttrNames for DocumentPath {
+    fn to_attr_names(&self) -> AttributeNames {
+        let mut m: AttributeNames = std::collections::HashMap::new();
+        m.insert(placeholder(&self.root), self.root.clone());
+        for element in self.elements.iter() {
+            if let PathElement::Field(name) = element {
+                m.insert(placeholder(name), name.clone());
+            }
+        }
+        m
+    }
+}
+
+// A `DocumentPath` which starts from an attribute of the struct `T` belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttrPath<T> {
+    path: DocumentPath,
+    _token: std::marker::PhantomData<T>,
+}
+
+impl<T: IntoAttrName> AttrPath<T> {
+    pub fn new(attr: T) -> Self {
+        Self {
+            path: DocumentPath::new(attr.into_attr_name()),
+            _token: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> AttrPath<T> {
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.path = self.path.field(name);
+        self
+    }
+
+    pub fn index(mut self, index: usize) -> Self {
+        self.path = self.path.index(index);
+        self
+    }
+
+    pub fn into_document_path(self) -> DocumentPath {
+        self.path
+    }
+}
+
+pub trait IntoAttrPath<T>: Sized {
+    fn into_attr_path(self) -> AttrPath<T>;
+}
+
+impl<T: IntoAttrName> IntoAttrPath<T> for T {
+    fn into_attr_path(self) -> AttrPath<T> {
+        AttrPath::new(self)
+    }
+}
+
+impl<T> IntoAttrPath<T> for AttrPath<T> {
+    fn into_attr_path(self) -> AttrPath<T> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum UserAttrNames {
+        Address,
+    }
+
+    impl IntoAttrName for UserAttrNames {
+        fn into_attr_name(self) -> String {
+            match self {
+                UserAttrNames::Address => "address".to_owned(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested_document_path() {
+        let path = UserAttrNames::Address
+            .into_attr_path()
+            .field("lines")
+            .index(2)
+            .field("city")
+            .into_document_path();
+        let mut expected_names: AttributeNames = std::collections::HashMap::new();
+        expected_names.insert("#address".to_owned(), "address".to_owned());
+        expected_names.insert("#lines".to_owned(), "lines".to_owned());
+        expected_names.insert("#city".to_owned(), "city".to_owned());
+        assert_eq!(path.to_string(), "#address.#lines[2].#city".to_owned());
+        assert_eq!(path.to_attr_names(), expected_names);
+    }
+
+    #[test]
+    fn test_document_path_with_invalid_placeholder_chars() {
+        let path = DocumentPath::new("user-id")
+            .field("first_name")
+            .field("a_b.c")
+            .field("_id");
+        let mut expected_names: AttributeNames = std::collections::HashMap::new();
+        expected_names.insert("#_user_2did".to_owned(), "user-id".to_owned());
+        expected_names.insert("#first_name".to_owned(), "first_name".to_owned());
+        expected_names.insert("#_a__b_2ec".to_owned(), "a_b.c".to_owned());
+        expected_names.insert("#___id".to_owned(), "_id".to_owned());
+        assert_eq!(
+            path.to_string(),
+            "#_user_2did.#first_name.#_a__b_2ec.#___id".to_owned()
+        );
+        assert_eq!(path.to_attr_names(), expected_names);
+
+        // Escaped names do not collide with names used as they are.
+        for (a, b) in [
+            ("user-id", "user_2did"),
+            ("user-id", "_user_2did"),
+            ("a-b", "a_2db"),
+        ] {
+            assert_ne!(placeholder(a), placeholder(b));
+        }
+    }
+}
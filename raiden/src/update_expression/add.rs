@@ -1,35 +1,38 @@
 use super::*;
 
 pub struct Add<T: super::IntoAttrName> {
-    target: T,
+    target: AttrPath<T>,
 }
 
 pub struct AddExpressionFilled<T: super::IntoAttrName> {
-    target: T,
-    value: (super::Placeholder, super::AttributeValue),
+    target: AttrPath<T>,
+    value: super::AttributeValue,
 }
 
 impl<T: super::IntoAttrName> Add<T> {
-    pub fn new(target: T) -> Self {
-        Self { target }
+    pub fn new(target: impl IntoAttrPath<T>) -> Self {
+        Self {
+            target: target.into_attr_path(),
+        }
     }
 
     pub fn value(self, value: impl super::IntoAttribute) -> AddExpressionFilled<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let value = (placeholder, value.into_attr());
+        let value = value.into_attr();
         let Add { target } = self;
         AddExpressionFilled::<T> { target, value }
     }
 }
 
 impl<T: super::IntoAttrName> UpdateAddExpressionBuilder for AddExpressionFilled<T> {
-    fn build(self) -> (String, super::AttributeNames, super::AttributeValues) {
-        let attr = self.target.into_attr_name();
-        let attr_name = format!("#{}", attr);
+    fn build_with(
+        self,
+        ctx: &mut super::ValueIdContext,
+    ) -> (String, super::AttributeNames, super::AttributeValues) {
+        let path = self.target.into_document_path();
 
         let mut names: super::AttributeNames = std::collections::HashMap::new();
         let mut values: super::AttributeValues = std::collections::HashMap::new();
-        let (placeholder, value) = self.value;
+        let value = self.value;
 
         // See. https://github.com/raiden-rs/raiden/issues/57
         //      https://github.com/raiden-rs/raiden/issues/58
@@ -37,8 +40,9 @@ impl<T: super::IntoAttrName> UpdateAddExpressionBuilder for AddExpressionFilled<
             return ("".to_owned(), names, values);
         }
 
-        names.insert(attr_name.clone(), attr);
-        let expression = format!("{} {}", attr_name, placeholder);
+        names = path.to_attr_names();
+        let placeholder = ctx.next_placeholder();
+        let expression = format!("{} {}", path, placeholder);
         values.insert(placeholder, value);
         (expression, names, values)
     }
@@ -68,7 +72,6 @@ mod tests {
 
     #[test]
     fn test_add_value_expression() {
-        crate::value_id::reset_value_id();
         let (expression, names, values) = Add::new(UserAttrNames::Age).value(42).build();
         let mut expected_names = std::collections::HashMap::new();
         let mut expected_values = std::collections::HashMap::new();
@@ -0,0 +1,38 @@
+use super::*;
+
+// Update actions on a single field, whose values are bound to the type of the field `V`.
+// e.g. `User::update_expression().age().add(1)`
+pub struct FieldUpdate<T: super::IntoAttrName, V> {
+    target: AttrPath<T>,
+    _value: std::marker::PhantomData<V>,
+}
+
+impl<T: super::IntoAttrName, V: super::IntoAttribute> FieldUpdate<T, V> {
+    pub fn new(target: impl IntoAttrPath<T>) -> Self {
+        Self {
+            target: target.into_attr_path(),
+            _value: std::marker::PhantomData,
+        }
+    }
+
+    pub fn set(self, value: impl Into<V>) -> SetExpressionFilledWithoutOperation<T> {
+        Set::new(self.target).value(value.into())
+    }
+
+    pub fn set_if_not_exists(self, value: impl Into<V>) -> SetExpressionFilledWithoutOperation<T> {
+        self.set(value).if_not_exists()
+    }
+}
+
+impl<T: super::IntoAttrName, V: super::IntoAttribute + super::AddOperand> FieldUpdate<T, V> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, value: impl Into<V>) -> AddExpressionFilled<T> {
+        Add::new(self.target).value(value.into())
+    }
+}
+
+impl<T: super::IntoAttrName, V: super::IntoAttribute + super::DeleteOperand> FieldUpdate<T, V> {
+    pub fn delete(self, value: impl Into<V>) -> DeleteExpressionFilled<T> {
+        Delete::new(self.target).value(value.into())
+    }
+}
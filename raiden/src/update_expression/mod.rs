@@ -1,29 +1,56 @@
 pub mod add;
 pub mod delete;
+pub mod field;
 pub mod set;
 
 pub use add::*;
 pub use delete::*;
+pub use field::*;
 pub use set::*;
 
 use super::{
-    generate_value_id, AttributeNames, AttributeValue, AttributeValues, IntoAttrName,
-    IntoAttribute, Placeholder,
+    merge_map, AddOperand, AttrPath, AttributeNames, AttributeValue, AttributeValues,
+    DeleteOperand, DocumentPath, IntoAttrName, IntoAttrPath, IntoAttribute, ToAttrNames,
+    ValueIdContext,
 };
 
+#[derive(Debug, Clone, PartialEq)]
 pub enum SetOrRemove {
     Set(String, AttributeNames, AttributeValues),
     Remove(String, AttributeNames),
 }
 
+// `build` issues placeholders from `:value0`. Use `build_with` to share a `ValueIdContext`
+// with other expressions of the same request.
 pub trait UpdateSetExpressionBuilder {
-    fn build(self) -> SetOrRemove;
+    fn build(self) -> SetOrRemove
+    where
+        Self: Sized,
+    {
+        self.build_with(&mut ValueIdContext::new())
+    }
+
+    fn build_with(self, ctx: &mut ValueIdContext) -> SetOrRemove;
 }
 
 pub trait UpdateAddExpressionBuilder {
-    fn build(self) -> (String, AttributeNames, AttributeValues);
+    fn build(self) -> (String, AttributeNames, AttributeValues)
+    where
+        Self: Sized,
+    {
+        self.build_with(&mut ValueIdContext::new())
+    }
+
+    fn build_with(self, ctx: &mut ValueIdContext) -> (String, AttributeNames, AttributeValues);
 }
 
 pub trait UpdateDeleteExpressionBuilder {
-    fn build(self) -> (String, AttributeNames, AttributeValues);
+    fn build(self) -> (String, AttributeNames, AttributeValues)
+    where
+        Self: Sized,
+    {
+        self.build_with(&mut ValueIdContext::new())
+    }
+
+    fn build_with(self, ctx: &mut ValueIdContext) -> (String, AttributeNames, AttributeValues);
 }
@@ -1,102 +1,167 @@
 use super::*;
 
 pub struct Set<T: super::IntoAttrName> {
-    target: T,
+    target: AttrPath<T>,
     index: Option<usize>,
-    _value: Option<SetValue<T>>,
+    _value: Option<Operand<T>>,
 }
 
 pub struct SetExpressionFilledWithoutOperation<T: super::IntoAttrName> {
-    target: T,
+    target: AttrPath<T>,
     index: Option<usize>,
-    value: SetValue<T>,
-    if_not_exists: bool,
+    value: Operand<T>,
+    if_not_exists: IfNotExists<T>,
 }
 
 pub struct SetExpressionFilled<T: super::IntoAttrName> {
-    target: T,
-    _index: Option<usize>,
-    value: SetValue<T>,
-    _if_not_exists: bool,
+    target: AttrPath<T>,
+    index: Option<usize>,
+    value: Operand<T>,
+    if_not_exists: IfNotExists<T>,
     operation: SetOperation,
     operand: Operand<T>,
 }
 
-enum SetOperation {
-    Add,
-    _Sub,
+// Which path `if_not_exists` checks, if any.
+enum IfNotExists<T: super::IntoAttrName> {
+    No,
+    Target,
+    At(AttrPath<T>),
 }
 
-impl std::fmt::Display for SetOperation {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match *self {
-            SetOperation::Add => write!(f, "+"),
-            SetOperation::_Sub => write!(f, "-"),
-        }
-    }
+enum SetOperation {
+    Add,
+    Sub,
+    // list_append(value, operand)
+    ListAppend,
+    // list_append(operand, value)
+    ListPrepend,
 }
 
 #[allow(clippy::large_enum_variant)]
-enum SetValue<T: super::IntoAttrName> {
-    Attr(T),
-    Value(super::Placeholder, super::AttributeValue),
-    // ListAppend(T, List)
+enum Operand<T: super::IntoAttrName> {
+    Attr(AttrPath<T>),
+    Value(super::AttributeValue),
 }
 
-#[allow(clippy::large_enum_variant)]
-enum Operand<T: super::IntoAttrName> {
-    _Attr(T),
-    Value(super::Placeholder, super::AttributeValue),
+impl<T: super::IntoAttrName> Operand<T> {
+    fn value(value: impl super::IntoAttribute) -> Self {
+        Operand::Value(value.into_attr())
+    }
+
+    fn attr(attr: impl IntoAttrPath<T>) -> Self {
+        Operand::Attr(attr.into_attr_path())
+    }
+
+    // Returns the operand as a string, and registers its names or values.
+    fn build(
+        self,
+        ctx: &mut super::ValueIdContext,
+        names: &mut super::AttributeNames,
+        values: &mut super::AttributeValues,
+    ) -> String {
+        match self {
+            Operand::Attr(a) => {
+                let path = a.into_document_path();
+                *names = merge_map(std::mem::take(names), path.to_attr_names());
+                path.to_string()
+            }
+            Operand::Value(value) => {
+                let placeholder = ctx.next_placeholder();
+                values.insert(placeholder.clone(), value);
+                placeholder
+            }
+        }
+    }
 }
 
 impl<T: super::IntoAttrName> Set<T> {
-    pub fn new(target: T) -> Self {
+    pub fn new(target: impl IntoAttrPath<T>) -> Self {
         Self {
-            target,
+            target: target.into_attr_path(),
             index: None,
             _value: None,
         }
     }
 
-    // For LIST/SET
+    // Set an element of LIST, as `#target[index] = :value`.
     pub fn index(mut self, index: usize) -> Self {
         self.index = Some(index);
         self
     }
 
     pub fn value(self, value: impl super::IntoAttribute) -> SetExpressionFilledWithoutOperation<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let value = SetValue::<T>::Value(placeholder, value.into_attr());
         let Set { target, index, .. } = self;
         SetExpressionFilledWithoutOperation::<T> {
             target,
             index,
-            value,
-            if_not_exists: false,
+            value: Operand::value(value),
+            if_not_exists: IfNotExists::No,
         }
     }
 
-    pub fn attr(self, attr: T) -> SetExpressionFilledWithoutOperation<T> {
-        let value = SetValue::<T>::Attr(attr);
+    pub fn attr(self, attr: impl IntoAttrPath<T>) -> SetExpressionFilledWithoutOperation<T> {
         let Set { target, index, .. } = self;
         SetExpressionFilledWithoutOperation::<T> {
             target,
             index,
-            value,
-            if_not_exists: false,
+            value: Operand::attr(attr),
+            if_not_exists: IfNotExists::No,
         }
     }
 }
 
 impl<T: super::IntoAttrName> SetExpressionFilledWithoutOperation<T> {
+    // Use the value only when the target does not exist, as `if_not_exists(#target, :value)`.
     pub fn if_not_exists(mut self) -> SetExpressionFilledWithoutOperation<T> {
-        self.if_not_exists = true;
+        self.if_not_exists = IfNotExists::Target;
+        self
+    }
+
+    // Use the value only when `path` does not exist, as `if_not_exists(#path, :value)`.
+    pub fn if_not_exists_at(
+        mut self,
+        path: impl IntoAttrPath<T>,
+    ) -> SetExpressionFilledWithoutOperation<T> {
+        self.if_not_exists = IfNotExists::At(path.into_attr_path());
         self
     }
 
     pub fn add_value(self, value: impl super::IntoAttribute) -> SetExpressionFilled<T> {
-        let placeholder = format!(":value{}", super::generate_value_id());
-        let operand = Operand::<T>::Value(placeholder, value.into_attr());
+        self.operate(SetOperation::Add, Operand::value(value))
+    }
+
+    pub fn add_attr(self, attr: impl IntoAttrPath<T>) -> SetExpressionFilled<T> {
+        self.operate(SetOperation::Add, Operand::attr(attr))
+    }
+
+    pub fn sub_value(self, value: impl super::IntoAttribute) -> SetExpressionFilled<T> {
+        self.operate(SetOperation::Sub, Operand::value(value))
+    }
+
+    pub fn sub_attr(self, attr: impl IntoAttrPath<T>) -> SetExpressionFilled<T> {
+        self.operate(SetOperation::Sub, Operand::attr(attr))
+    }
+
+    // Appends the given list to the end, as `list_append(#target, :value)`.
+    pub fn list_append_value(self, value: impl super::IntoAttribute) -> SetExpressionFilled<T> {
+        self.operate(SetOperation::ListAppend, Operand::value(value))
+    }
+
+    pub fn list_append_attr(self, attr: impl IntoAttrPath<T>) -> SetExpressionFilled<T> {
+        self.operate(SetOperation::ListAppend, Operand::attr(attr))
+    }
+
+    // Prepends the given list to the beginning, as `list_append(:value, #target)`.
+    pub fn list_prepend_value(self, value: impl super::IntoAttribute) -> SetExpressionFilled<T> {
+        self.operate(SetOperation::ListPrepend, Operand::value(value))
+    }
+
+    pub fn list_prepend_attr(self, attr: impl IntoAttrPath<T>) -> SetExpressionFilled<T> {
+        self.operate(SetOperation::ListPrepend, Operand::attr(attr))
+    }
+
+    fn operate(self, operation: SetOperation, operand: Operand<T>) -> SetExpressionFilled<T> {
         let SetExpressionFilledWithoutOperation {
             target,
             index,
@@ -105,85 +170,103 @@ impl<T: super::IntoAttrName> SetExpressionFilledWithoutOperation<T> {
         } = self;
         SetExpressionFilled::<T> {
             target,
-            _index: index,
+            index,
             value,
-            _if_not_exists: if_not_exists,
-            operation: SetOperation::Add,
+            if_not_exists,
+            operation,
             operand,
         }
     }
 }
 
+fn target_path<T>(target: AttrPath<T>, index: Option<usize>) -> DocumentPath {
+    match index {
+        Some(index) => target.index(index).into_document_path(),
+        None => target.into_document_path(),
+    }
+}
+
+// Returns `value`, or `if_not_exists(#path, value)` when `if_not_exists` is specified.
+fn build_value<T: super::IntoAttrName>(
+    target: &DocumentPath,
+    value: Operand<T>,
+    if_not_exists: IfNotExists<T>,
+    ctx: &mut super::ValueIdContext,
+    names: &mut super::AttributeNames,
+    values: &mut super::AttributeValues,
+) -> String {
+    let value = value.build(ctx, names, values);
+    match if_not_exists {
+        IfNotExists::No => value,
+        IfNotExists::Target => format!("if_not_exists({}, {})", target, value),
+        IfNotExists::At(path) => {
+            let path = path.into_document_path();
+            *names = merge_map(std::mem::take(names), path.to_attr_names());
+            format!("if_not_exists({}, {})", path, value)
+        }
+    }
+}
+
 impl<T: super::IntoAttrName> UpdateSetExpressionBuilder for SetExpressionFilledWithoutOperation<T> {
-    fn build(self) -> SetOrRemove {
-        let attr = self.target.into_attr_name();
-        let attr_name = format!("#{}", attr);
+    fn build_with(self, ctx: &mut super::ValueIdContext) -> SetOrRemove {
+        let path = target_path(self.target, self.index);
+        let attr_name = path.to_string();
 
-        let mut names: super::AttributeNames = std::collections::HashMap::new();
-        names.insert(attr_name.clone(), attr);
+        let mut names: super::AttributeNames = path.to_attr_names();
         let mut values: super::AttributeValues = std::collections::HashMap::new();
-        match self.value {
-            SetValue::Attr(a) => {
-                let set_attr = a.into_attr_name();
-                let set_attr_name = format!("#{}", set_attr);
-                let expression = format!("{} = {}", attr_name, set_attr_name);
-                names.insert(set_attr_name, set_attr);
-                SetOrRemove::Set(expression, names, values)
-            }
-            SetValue::Value(placeholder, value) => {
-                // See. https://github.com/raiden-rs/raiden/issues/57
-                //      https://github.com/raiden-rs/raiden/issues/58
-                if value == AttributeValue::default() {
-                    // Use remove instead of set
-                    return SetOrRemove::Remove(attr_name, names);
-                }
-                let expression = format!("{} = {}", attr_name, placeholder);
-                values.insert(placeholder, value);
-                SetOrRemove::Set(expression, names, values)
+
+        if let Operand::Value(value) = &self.value {
+            // See. https://github.com/raiden-rs/raiden/issues/57
+            //      https://github.com/raiden-rs/raiden/issues/58
+            if matches!(self.if_not_exists, IfNotExists::No) && value == &AttributeValue::default()
+            {
+                // Use remove instead of set
+                return SetOrRemove::Remove(attr_name, names);
             }
         }
+
+        let value = build_value(
+            &path,
+            self.value,
+            self.if_not_exists,
+            ctx,
+            &mut names,
+            &mut values,
+        );
+        let expression = format!("{} = {}", attr_name, value);
+        SetOrRemove::Set(expression, names, values)
     }
 }
 
 impl<T: super::IntoAttrName> UpdateSetExpressionBuilder for SetExpressionFilled<T> {
-    fn build(self) -> SetOrRemove {
-        let attr = self.target.into_attr_name();
-        let attr_name = format!("#{}", attr);
+    fn build_with(self, ctx: &mut super::ValueIdContext) -> SetOrRemove {
+        let path = target_path(self.target, self.index);
+        let attr_name = path.to_string();
 
-        let mut names: super::AttributeNames = std::collections::HashMap::new();
-        names.insert(attr_name.clone(), attr);
+        let mut names: super::AttributeNames = path.to_attr_names();
         let mut values: super::AttributeValues = std::collections::HashMap::new();
 
-        let op = format!("{}", self.operation);
-        let op_expression = match self.operand {
-            Operand::_Attr(a) => {
-                let operand_attr = a.into_attr_name();
-                let operand_attr_name = format!("#{}", operand_attr);
-                let val = format!("{} {}", op, operand_attr_name);
-                names.insert(operand_attr_name, operand_attr);
-                val
-            }
-            Operand::Value(placeholder, value) => {
-                let val = format!("{} {}", op, placeholder);
-                values.insert(placeholder, value);
-                val
-            }
-        };
+        let value = build_value(
+            &path,
+            self.value,
+            self.if_not_exists,
+            ctx,
+            &mut names,
+            &mut values,
+        );
+        let operand = self.operand.build(ctx, &mut names, &mut values);
 
-        match self.value {
-            SetValue::Attr(a) => {
-                let set_attr = a.into_attr_name();
-                let set_attr_name = format!("#{}", set_attr);
-                let expression = format!("{} = {} {}", attr_name, set_attr_name, op_expression);
-                names.insert(set_attr_name, set_attr);
-                SetOrRemove::Set(expression, names, values)
+        let expression = match self.operation {
+            SetOperation::Add => format!("{} = {} + {}", attr_name, value, operand),
+            SetOperation::Sub => format!("{} = {} - {}", attr_name, value, operand),
+            SetOperation::ListAppend => {
+                format!("{} = list_append({}, {})", attr_name, value, operand)
             }
-            SetValue::Value(placeholder, value) => {
-                let expression = format!("{} = {} {}", attr_name, placeholder, op_expression);
-                values.insert(placeholder, value);
-                SetOrRemove::Set(expression, names, values)
+            SetOperation::ListPrepend => {
+                format!("{} = list_append({}, {})", attr_name, operand, value)
             }
-        }
+        };
+        SetOrRemove::Set(expression, names, values)
     }
 }
 
@@ -213,7 +296,6 @@ mod tests {
 
     #[test]
     fn test_set_value_expression() {
-        crate::value_id::reset_value_id();
         if let SetOrRemove::Set(expression, names, values) =
             Set::new(UserAttrNames::Name).value("updated!!").build()
         {
@@ -231,7 +313,6 @@ mod tests {
 
     #[test]
     fn test_set_attr_expression_with_add_value() {
-        crate::value_id::reset_value_id();
         if let SetOrRemove::Set(expression, names, values) = Set::new(UserAttrNames::Age)
             .attr(UserAttrNames::Age)
             .add_value(10)
@@ -248,4 +329,142 @@ mod tests {
         }
         panic!("should not pass");
     }
+
+    #[test]
+    fn test_set_nested_path_expression() {
+        if let SetOrRemove::Set(expression, names, _values) =
+            Set::new(AttrPath::new(UserAttrNames::Name).field("first"))
+                .attr(AttrPath::new(UserAttrNames::Name).index(0))
+                .build()
+        {
+            let mut expected_names = std::collections::HashMap::new();
+            expected_names.insert("#name".to_owned(), "name".to_owned());
+            expected_names.insert("#first".to_owned(), "first".to_owned());
+            assert_eq!(expression, "#name.#first = #name[0]".to_owned(),);
+            assert_eq!(names, expected_names);
+            return;
+        }
+        panic!("should not pass");
+    }
+
+    #[test]
+    fn test_set_index_expression() {
+        if let SetOrRemove::Set(expression, names, values) = Set::new(UserAttrNames::Name)
+            .index(2)
+            .value("updated!!")
+            .build()
+        {
+            let mut expected_names = std::collections::HashMap::new();
+            expected_names.insert("#name".to_owned(), "name".to_owned());
+            let placeholder = values.keys().next().unwrap();
+            assert_eq!(expression, format!("#name[2] = {}", placeholder));
+            assert_eq!(names, expected_names);
+            return;
+        }
+        panic!("should not pass");
+    }
+
+    #[test]
+    fn test_set_attr_expression_with_sub_attr() {
+        if let SetOrRemove::Set(expression, names, values) = Set::new(UserAttrNames::Age)
+            .attr(UserAttrNames::Age)
+            .sub_attr(UserAttrNames::Name)
+            .build()
+        {
+            let mut expected_names = std::collections::HashMap::new();
+            expected_names.insert("#age".to_owned(), "age".to_owned());
+            expected_names.insert("#name".to_owned(), "name".to_owned());
+            assert_eq!(expression, "#age = #age - #name".to_owned(),);
+            assert_eq!(names, expected_names);
+            assert!(values.is_empty());
+            return;
+        }
+        panic!("should not pass");
+    }
+
+    #[test]
+    fn test_set_if_not_exists_expression_with_add_value() {
+        if let SetOrRemove::Set(expression, _names, values) = Set::new(UserAttrNames::Age)
+            .value(0)
+            .if_not_exists()
+            .add_value(1)
+            .build()
+        {
+            let mut placeholders = values.keys().cloned().collect::<Vec<_>>();
+            placeholders.sort_by_key(|p| values[p].n.clone());
+            assert_eq!(
+                expression,
+                format!(
+                    "#age = if_not_exists(#age, {}) + {}",
+                    placeholders[0], placeholders[1]
+                )
+            );
+            return;
+        }
+        panic!("should not pass");
+    }
+
+    #[test]
+    fn test_set_if_not_exists_at_expression_with_add_value() {
+        if let SetOrRemove::Set(expression, names, values) = Set::new(UserAttrNames::Age)
+            .value(0)
+            .if_not_exists_at(UserAttrNames::Name)
+            .add_value(1)
+            .build()
+        {
+            let mut expected_names = std::collections::HashMap::new();
+            expected_names.insert("#age".to_owned(), "age".to_owned());
+            expected_names.insert("#name".to_owned(), "name".to_owned());
+            let mut placeholders = values.keys().cloned().collect::<Vec<_>>();
+            placeholders.sort_by_key(|p| values[p].n.clone());
+            assert_eq!(
+                expression,
+                format!(
+                    "#age = if_not_exists(#name, {}) + {}",
+                    placeholders[0], placeholders[1]
+                )
+            );
+            assert_eq!(names, expected_names);
+            return;
+        }
+        panic!("should not pass");
+    }
+
+    #[test]
+    fn test_set_list_append_expression() {
+        if let SetOrRemove::Set(expression, _names, values) = Set::new(UserAttrNames::Name)
+            .attr(UserAttrNames::Name)
+            .list_append_value(vec!["foo"])
+            .build()
+        {
+            let placeholder = values.keys().next().unwrap();
+            assert_eq!(
+                expression,
+                format!("#name = list_append(#name, {})", placeholder)
+            );
+            return;
+        }
+        panic!("should not pass");
+    }
+
+    #[test]
+    fn test_set_list_prepend_expression() {
+        if let SetOrRemove::Set(expression, _names, values) = Set::new(UserAttrNames::Name)
+            .value(vec!["foo"])
+            .if_not_exists()
+            .list_prepend_attr(UserAttrNames::Age)
+            .build()
+        {
+            let placeholder = values.keys().next().unwrap();
+            assert_eq!(
+                expression,
+                format!(
+                    "#name = list_append(#age, if_not_exists(#name, {}))",
+                    placeholder
+                )
+            );
+            return;
+        }
+        panic!("should not pass");
+    }
 }
@@ -1,16 +1,67 @@
-use std::sync::atomic::AtomicUsize;
-static VALUE_ID: AtomicUsize = AtomicUsize::new(0);
+// Issues `:valueN` placeholders while expressions are built.
+// A request builder owns one context and passes it to every expression it merges,
+// so placeholders never collide within a request and identical builds render identical expressions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueIdContext {
+    next_id: usize,
+}
 
-pub fn generate_value_id() -> usize {
-    use std::sync::atomic::Ordering;
+impl ValueIdContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_placeholder(&mut self) -> super::Placeholder {
+        let id = self.next_id;
+        self.next_id += 1;
+        format!(":value{}", id)
+    }
+}
+
+// The global counter which `ValueIdContext` replaced. Placeholders no longer come from it,
+// so these are kept only to let existing callers, e.g. tests resetting it, compile.
+static VALUE_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
 
-    let id = VALUE_ID.load(Ordering::Relaxed);
-    VALUE_ID.store(id.wrapping_add(1), Ordering::Relaxed);
-    id
+#[deprecated(note = "placeholders are issued per request by `ValueIdContext`")]
+pub fn generate_value_id() -> usize {
+    VALUE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
 }
 
+#[deprecated(
+    note = "placeholders are issued per request by `ValueIdContext`, so there is nothing to reset"
+)]
 pub fn reset_value_id() {
-    use std::sync::atomic::Ordering;
+    VALUE_ID.store(0, std::sync::atomic::Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_value_id_context() {
+        let mut ctx = ValueIdContext::new();
+        assert_eq!(ctx.next_placeholder(), ":value0".to_owned());
+        assert_eq!(ctx.next_placeholder(), ":value1".to_owned());
+        assert_eq!(
+            ValueIdContext::new().next_placeholder(),
+            ":value0".to_owned()
+        );
+    }
 
-    VALUE_ID.store(0, Ordering::Relaxed);
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_value_id() {
+        reset_value_id();
+        generate_value_id();
+        // Requests do not depend on the global counter anymore.
+        assert_eq!(
+            ValueIdContext::new().next_placeholder(),
+            ":value0".to_owned()
+        );
+    }
 }
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use raiden::*;
+
+    #[derive(Raiden, Debug, Clone, PartialEq)]
+    pub struct BatchWriteTest0 {
+        #[raiden(partition_key)]
+        id: String,
+        name: String,
+    }
+
+    #[derive(Raiden, Debug, Clone, PartialEq)]
+    pub struct BatchWriteTest1 {
+        #[raiden(partition_key)]
+        id: String,
+        #[raiden(sort_key)]
+        year: usize,
+        name: String,
+    }
+
+    #[test]
+    fn test_batch_write_put_items() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = BatchWriteTest0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+
+            // More than 25 items to make sure the requests are split into chunks.
+            let builder = (0..60).fold(client.batch_write(), |builder, n| {
+                builder.put(
+                    BatchWriteTest0::put_item_builder()
+                        .id(format!("id{}", n))
+                        .name("bob".to_owned())
+                        .build(),
+                )
+            });
+            let res = builder.run().await.unwrap();
+            assert_eq!(res.unprocessed_items, vec![]);
+            // The capacity of all chunks is summed up into a single entry of the table.
+            let capacity = res.consumed_capacity.unwrap();
+            assert_eq!(capacity.len(), 1);
+            assert_eq!(capacity[0].table_name, Some("BatchWriteTest0".to_owned()));
+            assert!(capacity[0].capacity_units.unwrap() >= 60.0);
+
+            let keys: Vec<String> = (0..60).map(|n| format!("id{}", n)).collect();
+            let res = client.batch_get(keys).run().await.unwrap();
+            assert_eq!(res.items.len(), 60);
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_batch_write_delete_items_with_sort_key() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = BatchWriteTest1::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+
+            let res = client
+                .batch_write()
+                .delete("id0", 2000_usize)
+                .delete("id1", 2001_usize)
+                .put(
+                    BatchWriteTest1::put_item_builder()
+                        .id("id2".to_owned())
+                        .year(2002)
+                        .name("alice".to_owned())
+                        .build(),
+                )
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(res.unprocessed_items, vec![]);
+
+            let res = client
+                .batch_get(vec![("id0", 2000_usize), ("id1", 2001), ("id2", 2002)])
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.items,
+                vec![BatchWriteTest1 {
+                    id: "id2".to_owned(),
+                    year: 2002,
+                    name: "alice".to_owned(),
+                }]
+            );
+        }
+        rt.block_on(example());
+    }
+}
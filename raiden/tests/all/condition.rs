@@ -158,9 +158,7 @@ mod tests {
 
     #[test]
     fn test_cmp_eq_attr_attr_condition() {
-        let cond = User::condition()
-            .attr(User::name())
-            .eq_attr(User::name());
+        let cond = User::condition().attr(User::name()).eq_attr(User::name());
         let (condition_expression, attribute_names, _attribute_values) = cond.build();
         let mut expected_names: std::collections::HashMap<String, String> =
             std::collections::HashMap::new();
@@ -171,10 +169,7 @@ mod tests {
 
     #[test]
     fn test_cmp_eq_value_attr_condition() {
-        reset_value_id();
-        let cond = User::condition()
-            .value("bokuweb")
-            .eq_attr(User::name());
+        let cond = User::condition().value("bokuweb").eq_attr(User::name());
         let (condition_expression, attribute_names, attribute_values) = cond.build();
         let mut expected_names: std::collections::HashMap<String, String> =
             std::collections::HashMap::new();
@@ -191,4 +186,522 @@ mod tests {
         assert_eq!(attribute_names, expected_names);
         assert_eq!(attribute_values, expected_values);
     }
+
+    #[test]
+    fn test_cmp_ne_attr_attr_condition() {
+        let cond = User::condition().attr(User::id()).ne_attr(User::name());
+        let (condition_expression, attribute_names, _attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(condition_expression, "#id <> #name".to_owned(),);
+        assert_eq!(attribute_names, expected_names);
+    }
+
+    #[test]
+    fn test_cmp_lt_le_gt_ge_attr_condition() {
+        let (lt, _, _) = User::condition()
+            .attr(User::id())
+            .lt_attr(User::name())
+            .build();
+        let (le, _, _) = User::condition()
+            .attr(User::id())
+            .le_attr(User::name())
+            .build();
+        let (gt, _, _) = User::condition()
+            .attr(User::id())
+            .gt_attr(User::name())
+            .build();
+        let (ge, _, _) = User::condition()
+            .attr(User::id())
+            .ge_attr(User::name())
+            .build();
+        assert_eq!(lt, "#id < #name".to_owned());
+        assert_eq!(le, "#id <= #name".to_owned());
+        assert_eq!(gt, "#id > #name".to_owned());
+        assert_eq!(ge, "#id >= #name".to_owned());
+    }
+
+    #[test]
+    fn test_cmp_lt_value_condition() {
+        let cond = User::condition()
+            .not()
+            .attr(User::name())
+            .lt_value("bokuweb");
+        let (condition_expression, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(attribute_names, expected_names);
+        assert_eq!(attribute_values.len(), 1);
+        let (placeholder, value) = attribute_values.into_iter().next().unwrap();
+        assert_eq!(
+            condition_expression,
+            format!("NOT (#name < {})", placeholder)
+        );
+        assert_eq!(value.s, Some("bokuweb".to_owned()));
+    }
+
+    #[test]
+    fn test_cmp_between_attr_condition() {
+        let cond = User::condition()
+            .attr(User::name())
+            .between_attr(User::id(), User::id());
+        let (condition_expression, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(condition_expression, "#name BETWEEN #id AND #id".to_owned());
+        assert_eq!(attribute_names, expected_names);
+        assert!(attribute_values.is_empty());
+    }
+
+    #[test]
+    fn test_cmp_between_value_condition() {
+        let cond = User::condition().attr(User::name()).between_value("a", "m");
+        let (condition_expression, _attribute_names, attribute_values) = cond.build();
+        assert_eq!(attribute_values.len(), 2);
+        let placeholders = condition_expression
+            .trim_start_matches("#name BETWEEN ")
+            .split(" AND ")
+            .collect::<Vec<_>>();
+        assert_eq!(placeholders.len(), 2);
+        assert_eq!(attribute_values[placeholders[0]].s, Some("a".to_owned()));
+        assert_eq!(attribute_values[placeholders[1]].s, Some("m".to_owned()));
+    }
+
+    #[test]
+    fn test_cmp_in_value_condition() {
+        let cond = User::condition()
+            .attr(User::name())
+            .in_value(vec!["bob", "alice", "john"]);
+        let (condition_expression, _attribute_names, attribute_values) = cond.build();
+        assert_eq!(attribute_values.len(), 3);
+        assert!(condition_expression.starts_with("#name IN ("));
+        let placeholders = condition_expression
+            .trim_start_matches("#name IN (")
+            .trim_end_matches(')')
+            .split(", ")
+            .collect::<Vec<_>>();
+        let values = placeholders
+            .iter()
+            .map(|p| attribute_values[*p].s.clone().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec!["bob", "alice", "john"]);
+    }
+
+    #[test]
+    fn test_cmp_in_attr_condition() {
+        let cond = User::condition()
+            .value("bokuweb")
+            .in_attr(vec![User::id(), User::name()]);
+        let (condition_expression, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(attribute_names, expected_names);
+        assert_eq!(attribute_values.len(), 1);
+        let placeholder = attribute_values.keys().next().unwrap().clone();
+        assert_eq!(
+            condition_expression,
+            format!("{} IN (#id, #name)", placeholder)
+        );
+    }
+
+    #[test]
+    fn test_size_condition() {
+        let cond = User::condition().size(User::name()).gt_value(3);
+        let (condition_expression, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(attribute_names, expected_names);
+        assert_eq!(attribute_values.len(), 1);
+        let (placeholder, value) = attribute_values.into_iter().next().unwrap();
+        assert_eq!(
+            condition_expression,
+            format!("size(#name) > {}", placeholder)
+        );
+        assert_eq!(value.n, Some("3".to_owned()));
+    }
+
+    #[test]
+    fn test_size_attr_condition() {
+        let cond = User::condition()
+            .not()
+            .size(User::name())
+            .le_attr(User::id());
+        let (condition_expression, attribute_names, _attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(condition_expression, "NOT (size(#name) <= #id)".to_owned());
+        assert_eq!(attribute_names, expected_names);
+    }
+
+    #[test]
+    fn test_or_condition() {
+        let cond = User::condition()
+            .attr_exists(User::name())
+            .or(User::condition().attr_exists(User::id()));
+        let (condition_expression, attribute_names, _attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(
+            condition_expression,
+            "attribute_exists(#name) OR (attribute_exists(#id))".to_owned(),
+        );
+        assert_eq!(attribute_names, expected_names);
+    }
+
+    #[test]
+    fn test_and_or_chain_condition() {
+        let cond = User::condition()
+            .attr_exists(User::name())
+            .or(User::condition().attr_exists(User::id()))
+            .and(User::condition().attr_not_exists(User::name()))
+            .or(User::condition().not().attr_exists(User::id()));
+        let (condition_expression, _attribute_names, _attribute_values) = cond.build();
+        assert_eq!(
+            condition_expression,
+            "(attribute_exists(#name) OR (attribute_exists(#id))) AND (attribute_not_exists(#name)) OR (NOT (attribute_exists(#id)))".to_owned(),
+        );
+    }
+
+    #[test]
+    fn test_nested_group_condition() {
+        let a = User::condition()
+            .attr(User::name())
+            .eq_value("bokuweb")
+            .and(User::condition().attr_exists(User::id()));
+        let b = User::condition()
+            .attr(User::name())
+            .eq_value("raiden")
+            .and(User::condition().not().attr_exists(User::id()));
+        let cond = User::condition().not().group(a.or(b));
+        let (condition_expression, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        assert_eq!(attribute_names, expected_names);
+        assert_eq!(attribute_values.len(), 2);
+
+        let mut placeholders = attribute_values.keys().cloned().collect::<Vec<_>>();
+        placeholders.sort_by_key(|p| attribute_values[p].s.clone());
+        assert_eq!(
+            condition_expression,
+            format!(
+                "NOT (#name = {} AND (attribute_exists(#id)) OR (#name = {} AND (NOT (attribute_exists(#id)))))",
+                placeholders[0], placeholders[1]
+            )
+        );
+    }
+
+    #[test]
+    fn test_nested_path_condition() {
+        let cond = User::condition()
+            .attr_exists(User::name().field("first"))
+            .and(
+                User::condition()
+                    .size(User::id().index(0))
+                    .eq_attr(User::name().field("last").index(1)),
+            );
+        let (condition_expression, attribute_names, _attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        expected_names.insert("#first".to_owned(), "first".to_owned());
+        expected_names.insert("#last".to_owned(), "last".to_owned());
+        assert_eq!(
+            condition_expression,
+            "attribute_exists(#name.#first) AND (size(#id[0]) = #name.#last[1])".to_owned(),
+        );
+        assert_eq!(attribute_names, expected_names);
+    }
+
+    #[test]
+    fn test_condition_with_value_id_context() {
+        let cond = User::condition()
+            .attr(User::name())
+            .eq_value("bokuweb")
+            .or(User::condition().attr(User::id()).in_value(vec!["a", "b"]));
+        let (first, _, _) = cond.clone().build();
+        let (second, _, _) = cond.clone().build();
+        assert_eq!(first, second);
+        assert_eq!(
+            first,
+            "#name = :value0 OR (#id IN (:value1, :value2))".to_owned()
+        );
+
+        let mut ctx = ValueIdContext::new();
+        ctx.next_placeholder();
+        let (condition_expression, _attribute_names, attribute_values) = cond.build_with(&mut ctx);
+        assert_eq!(
+            condition_expression,
+            "#name = :value1 OR (#id IN (:value2, :value3))".to_owned()
+        );
+        assert_eq!(attribute_values[":value1"].s, Some("bokuweb".to_owned()));
+        assert_eq!(ctx.next_placeholder(), ":value4".to_owned());
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "user")]
+    #[derive(Debug, Clone)]
+    pub struct TypedUser {
+        #[raiden(partition_key)]
+        id: String,
+        name: Option<String>,
+        age: u32,
+        tags: std::collections::HashSet<String>,
+        scores: std::collections::HashSet<u32>,
+        history: Vec<u32>,
+    }
+
+    #[test]
+    fn test_typed_field_condition() {
+        let cond = TypedUser::cond()
+            .age()
+            .gt(30u32)
+            .and(TypedUser::cond().name().begins_with("boku"))
+            .and(TypedUser::cond().tags().contains("admin"))
+            .and(TypedUser::cond().id().not().in_list(vec!["id0", "id1"]));
+        let (condition_expression, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        expected_names.insert("#age".to_owned(), "age".to_owned());
+        expected_names.insert("#tags".to_owned(), "tags".to_owned());
+        assert_eq!(
+            condition_expression,
+            "#age > :value0 AND (begins_with(#name, :begins_with_17d8e2e8233d9a6ae428061cb2cdf226)) AND (contains(#tags, :contains_4d689079cdfecacb5784a5c19d9687cb)) AND (NOT (#id IN (:value1, :value2)))".to_owned(),
+        );
+        assert_eq!(attribute_names, expected_names);
+        assert_eq!(attribute_values[":value0"], 30.into_attr());
+        assert_eq!(attribute_values[":value1"], "id0".into_attr());
+    }
+
+    #[test]
+    fn test_typed_field_condition_with_option() {
+        let cond = TypedUser::cond()
+            .name()
+            .eq("bokuweb".to_owned())
+            .or(TypedUser::cond().age().between(20u32, 30u32));
+        let (condition_expression, _attribute_names, attribute_values) = cond.build();
+        assert_eq!(
+            condition_expression,
+            "#name = :value0 OR (#age BETWEEN :value1 AND :value2)".to_owned(),
+        );
+        assert_eq!(attribute_values[":value0"], "bokuweb".into_attr());
+    }
+
+    #[test]
+    fn test_contains_non_string_condition() {
+        let cond = TypedUser::cond()
+            .scores()
+            .in_set(3u32)
+            .and(TypedUser::cond().history().contains(3u32));
+        let (condition_expression, _attribute_names, attribute_values) = cond.clone().build();
+        let placeholder = attribute_values.keys().next().unwrap().clone();
+        // The same value gets the same placeholder.
+        assert_eq!(attribute_values.len(), 1);
+        assert_eq!(attribute_values[&placeholder], 3.into_attr());
+        assert_eq!(
+            condition_expression,
+            format!(
+                "contains(#scores, {}) AND (contains(#history, {}))",
+                placeholder, placeholder
+            ),
+        );
+
+        let (_, _, attribute_values) = TypedUser::condition()
+            .contains(TypedUser::tags(), "3")
+            .build();
+        assert!(!attribute_values.contains_key(&placeholder));
+
+        let mut item: raiden::AttributeValues = std::collections::HashMap::new();
+        item.insert(
+            "scores".to_owned(),
+            AttributeValue {
+                ns: Some(vec!["1".to_owned(), "3.0".to_owned()]),
+                ..AttributeValue::default()
+            },
+        );
+        item.insert("history".to_owned(), vec![2u32, 3].into_attr());
+        assert!(cond.evaluate(&item));
+        assert!(!TypedUser::cond().scores().in_set(2u32).evaluate(&item));
+    }
+
+    fn user_item(id: &str, name: Option<&str>) -> raiden::AttributeValues {
+        let mut item: raiden::AttributeValues = std::collections::HashMap::new();
+        item.insert("id".to_owned(), id.into_attr());
+        if let Some(name) = name {
+            item.insert("name".to_owned(), name.into_attr());
+        }
+        item
+    }
+
+    #[test]
+    fn test_evaluate_comparison_condition() {
+        let item = user_item("id0", Some("bokuweb"));
+        assert!(User::condition()
+            .attr(User::name())
+            .eq_value("bokuweb")
+            .evaluate(&item));
+        assert!(User::condition()
+            .attr(User::name())
+            .between_value("a", "c")
+            .evaluate(&item));
+        assert!(User::condition()
+            .attr(User::id())
+            .in_value(vec!["id1", "id0"])
+            .evaluate(&item));
+        assert!(User::condition()
+            .size(User::name())
+            .gt_value(6)
+            .evaluate(&item));
+        assert!(!User::condition()
+            .size(User::name())
+            .gt_value(7)
+            .evaluate(&item));
+        // A value of another type never matches.
+        assert!(!User::condition()
+            .attr(User::id())
+            .lt_value(1)
+            .evaluate(&item));
+        assert!(User::condition()
+            .attr(User::id())
+            .ne_value(0)
+            .evaluate(&item));
+    }
+
+    #[test]
+    fn test_evaluate_condition_with_missing_attribute() {
+        let item = user_item("id0", None);
+        assert!(User::condition()
+            .attr_not_exists(User::name())
+            .evaluate(&item));
+        assert!(!User::condition()
+            .attr(User::name())
+            .eq_value("bokuweb")
+            .evaluate(&item));
+        assert!(!User::condition()
+            .attr(User::name())
+            .lt_value("bokuweb")
+            .evaluate(&item));
+        assert!(User::condition()
+            .attr(User::name())
+            .ne_value("bokuweb")
+            .evaluate(&item));
+    }
+
+    #[test]
+    fn test_evaluate_function_and_logical_condition() {
+        let item = user_item("id0", Some("bokuweb"));
+        let cond = User::condition()
+            .begins_with(User::name(), "boku")
+            .and(User::condition().contains(User::name(), "web"))
+            .and(
+                User::condition()
+                    .not()
+                    .attr_type(User::id(), raiden::AttributeType::N),
+            );
+        assert!(cond.evaluate(&item));
+        assert!(!User::condition().not().group(cond).evaluate(&item));
+        assert!(User::condition()
+            .attr_not_exists(User::id())
+            .or(User::condition().attr_exists(User::name()))
+            .evaluate(&item));
+    }
+
+    #[test]
+    fn test_evaluate_nested_path_condition() {
+        let mut address: std::collections::HashMap<String, raiden::AttributeValue> =
+            std::collections::HashMap::new();
+        address.insert("city".to_owned(), "Tokyo".into_attr());
+        let mut item = user_item("id0", None);
+        item.insert(
+            "name".to_owned(),
+            raiden::AttributeValue {
+                l: Some(vec![raiden::AttributeValue {
+                    m: Some(address),
+                    ..raiden::AttributeValue::default()
+                }]),
+                ..raiden::AttributeValue::default()
+            },
+        );
+        assert!(User::condition()
+            .attr(User::name().index(0).field("city"))
+            .eq_value("Tokyo")
+            .evaluate(&item));
+        assert!(User::condition()
+            .attr_not_exists(User::name().index(1).field("city"))
+            .evaluate(&item));
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "BinaryTestData0")]
+    #[derive(Debug, Clone)]
+    pub struct BinaryItem {
+        #[raiden(partition_key)]
+        id: Binary,
+        digest: Binary,
+    }
+
+    #[test]
+    fn test_binary_begins_with_condition() {
+        let cond = BinaryItem::cond().digest().begins_with(vec![0xde, 0xad]);
+        let (condition_expression, _attribute_names, attribute_values) = cond.clone().build();
+        let placeholder = attribute_values.keys().next().unwrap().clone();
+        assert!(placeholder.starts_with(":begins_with_"));
+        assert_eq!(
+            condition_expression,
+            format!("begins_with(#digest, {})", placeholder)
+        );
+        assert_eq!(
+            attribute_values[&placeholder],
+            Binary(vec![0xde, 0xad]).into_attr()
+        );
+
+        let mut item: raiden::AttributeValues = std::collections::HashMap::new();
+        item.insert(
+            "digest".to_owned(),
+            Binary(vec![0xde, 0xad, 0xbe, 0xef]).into_attr(),
+        );
+        assert!(cond.evaluate(&item));
+        assert!(!BinaryItem::cond()
+            .digest()
+            .begins_with(vec![0xbe])
+            .evaluate(&item));
+        // A string prefix never matches a binary.
+        assert!(!BinaryItem::condition()
+            .begins_with(BinaryItem::digest(), "de")
+            .evaluate(&item));
+    }
+
+    #[test]
+    fn test_parsed_condition() {
+        let mut names: AttributeNames = std::collections::HashMap::new();
+        names.insert("#n".to_owned(), "name".to_owned());
+        let mut values: AttributeValues = std::collections::HashMap::new();
+        values.insert(":v".to_owned(), "bokuweb".into_attr());
+        let cond = raiden::expression::parse_condition("#n = :v", &names, &values).unwrap();
+        // A parsed condition is used for a struct only through its condition token.
+        let cond =
+            UserConditionToken::from_parsed(cond).and(User::condition().attr_exists(User::id()));
+        let (condition_expression, attribute_names, _attribute_values) = cond.build();
+        assert_eq!(
+            condition_expression,
+            "#name = :value0 AND (attribute_exists(#id))".to_owned()
+        );
+        assert_eq!(attribute_names.len(), 2);
+    }
 }
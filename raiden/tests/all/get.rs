@@ -121,6 +121,7 @@ mod tests {
                 // }),
                 Err(RaidenError::AttributeConvertError {
                     attr_name: "unstored".to_owned(),
+                    source: ConversionError::ValueIsNone,
                 }),
             );
         }
@@ -534,4 +535,357 @@ mod tests {
         }
         rt.block_on(example());
     }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    pub struct Geo {
+        lat: f64,
+        lng: f64,
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    pub struct Address {
+        city: String,
+        geo: Geo,
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    #[raiden(rename_all = "camelCase")]
+    pub struct Settings {
+        dark_mode: bool,
+        #[raiden(use_default)]
+        font_size: usize,
+        #[raiden(rename = "lang")]
+        language: Option<String>,
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "NestedTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NestedTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        address: Address,
+        settings: Option<Settings>,
+        past_addresses: Vec<Address>,
+    }
+
+    #[test]
+    fn test_get_nested_struct() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = NestedTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0").run().await;
+            assert_eq!(
+                res.unwrap().item,
+                NestedTestData0 {
+                    id: "id0".to_owned(),
+                    address: Address {
+                        city: "Tokyo".to_owned(),
+                        geo: Geo {
+                            lat: 35.68,
+                            lng: 139.76,
+                        },
+                    },
+                    settings: Some(Settings {
+                        dark_mode: true,
+                        font_size: 0,
+                        language: Some("ja".to_owned()),
+                    }),
+                    past_addresses: vec![Address {
+                        city: "Osaka".to_owned(),
+                        geo: Geo {
+                            lat: 34.69,
+                            lng: 135.5,
+                        },
+                    }],
+                }
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    #[raiden(rename_all = "snake_case")]
+    pub enum Status {
+        Todo,
+        InProgress,
+        #[raiden(rename = "finished")]
+        Done,
+    }
+
+    #[derive(RaidenAttribute, Debug, Clone, PartialEq)]
+    #[raiden(tag = "kind")]
+    #[raiden(content = "data")]
+    pub enum Shape {
+        Point,
+        Circle(f64),
+        Line(f64, f64),
+        Rect { width: f64, height: f64 },
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "EnumTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct EnumTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        status: Status,
+        shape: Shape,
+        history: Vec<Shape>,
+    }
+
+    #[test]
+    fn test_get_enum() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = EnumTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0").run().await;
+            assert_eq!(
+                res.unwrap().item,
+                EnumTestData0 {
+                    id: "id0".to_owned(),
+                    status: Status::InProgress,
+                    shape: Shape::Rect {
+                        width: 2.0,
+                        height: 3.0,
+                    },
+                    history: vec![Shape::Point, Shape::Circle(1.5), Shape::Line(1.0, 2.0)],
+                }
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "MapTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MapTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        scores: std::collections::HashMap<String, u32>,
+        labels: std::collections::BTreeMap<String, String>,
+        empty: std::collections::HashMap<String, String>,
+    }
+
+    #[test]
+    fn test_get_map() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = MapTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0").run().await;
+            let mut scores = std::collections::HashMap::new();
+            scores.insert("alice".to_owned(), 10);
+            scores.insert("bob".to_owned(), 20);
+            let mut labels = std::collections::BTreeMap::new();
+            labels.insert("env".to_owned(), "prod".to_owned());
+            labels.insert("team".to_owned(), "core".to_owned());
+            assert_eq!(
+                res.unwrap().item,
+                MapTestData0 {
+                    id: "id0".to_owned(),
+                    scores,
+                    labels,
+                    empty: std::collections::HashMap::new(),
+                }
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[cfg(feature = "json")]
+    #[derive(Raiden)]
+    #[raiden(table_name = "MapTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JsonTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        metadata: serde_json::Value,
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_get_json() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = JsonTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0").run().await;
+            assert_eq!(
+                res.unwrap().item,
+                JsonTestData0 {
+                    id: "id0".to_owned(),
+                    metadata: serde_json::json!({
+                        "version": 2,
+                        "ratio": 0.5,
+                        "tags": ["a", [1, true]],
+                        "owner": null,
+                    }),
+                }
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "BinaryTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BinaryTestData0 {
+        #[raiden(partition_key)]
+        id: Binary,
+        #[raiden(sort_key)]
+        chunk: Binary,
+        data: bytes::Bytes,
+        hashes: std::collections::HashSet<Binary>,
+    }
+
+    #[test]
+    fn test_get_binary() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = BinaryTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get(vec![1], vec![0, 1]).run().await;
+            let mut hashes = std::collections::HashSet::new();
+            hashes.insert(Binary(vec![0xde, 0xad]));
+            hashes.insert(Binary(vec![0xbe, 0xef]));
+            assert_eq!(
+                res.unwrap().item,
+                BinaryTestData0 {
+                    id: Binary(vec![1]),
+                    chunk: Binary(vec![0, 1]),
+                    data: bytes::Bytes::from_static(b"hello"),
+                    hashes,
+                }
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "NumberTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NumberTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        wide: i128,
+    }
+
+    #[test]
+    fn test_get_wide_number() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = NumberTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0").run().await;
+            assert_eq!(
+                res.unwrap().item,
+                NumberTestData0 {
+                    id: "id0".to_owned(),
+                    wide: 12_345_678_901_234_567_890_123_456_789_012_345_678,
+                }
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_get_fractional_number_into_integer() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = NumberTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let err = client.get("id1").run().await.unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "attribute \"wide\" convert error: Parsing error of number \"1.5\" into i128"
+                    .to_owned()
+            );
+            assert_eq!(
+                err,
+                RaidenError::AttributeConvertError {
+                    attr_name: "wide".to_owned(),
+                    source: ConversionError::ParseNumber("1.5".to_owned(), "i128".to_owned()),
+                },
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[derive(Raiden)]
+    #[raiden(table_name = "NumberTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DecimalTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        amount: rust_decimal::Decimal,
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_get_decimal() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = DecimalTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0").run().await;
+            assert_eq!(
+                res.unwrap().item.amount.to_string(),
+                "1234567890.123456789".to_owned()
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[derive(Raiden)]
+    #[raiden(table_name = "NumberTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BigDecimalTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        wide: bigdecimal::BigDecimal,
+        amount: bigdecimal::BigDecimal,
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn test_get_big_decimal() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = BigDecimalTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0").run().await.unwrap();
+            assert_eq!(
+                res.item.wide.to_string(),
+                "12345678901234567890123456789012345678".to_owned()
+            );
+            assert_eq!(
+                res.item.amount.to_string(),
+                "1234567890.123456789".to_owned()
+            );
+        }
+        rt.block_on(example());
+    }
 }
@@ -20,7 +20,6 @@ mod tests {
 
     #[test]
     fn test_eq_key_condition() {
-        reset_value_id();
         let cond = User::key_condition(User::name()).eq("bokuweb");
         let (key_condition, attribute_names, attribute_values) = cond.build();
         let mut expected_names: std::collections::HashMap<String, String> =
@@ -36,8 +35,6 @@ mod tests {
 
     #[test]
     fn test_two_and_key_condition() {
-        reset_value_id();
-
         let cond = User::key_condition(User::name()).eq("bokuweb").and(
             User::key_condition(User::year())
                 .eq(1999)
@@ -66,8 +63,6 @@ mod tests {
 
     #[test]
     fn test_begins_with_key_condition() {
-        reset_value_id();
-
         let cond = User::key_condition(User::name()).begins_with("bokuweb");
         let (key_condition, attribute_names, attribute_values) = cond.build();
         let mut expected_names: std::collections::HashMap<String, String> =
@@ -83,8 +78,6 @@ mod tests {
 
     #[test]
     fn test_begins_with_id_and_key_condition() {
-        reset_value_id();
-
         let cond = User::key_condition(User::id())
             .eq("id3")
             .and(User::key_condition(User::year()).begins_with("20"));
@@ -94,4 +87,108 @@ mod tests {
             "#id = :value0 AND (begins_with(#year, :value1))".to_owned(),
         );
     }
+
+    #[test]
+    fn test_nested_path_key_condition() {
+        let cond = User::key_condition(User::name().field("first")).begins_with("boku");
+        let (key_condition, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#name".to_owned(), "name".to_owned());
+        expected_names.insert("#first".to_owned(), "first".to_owned());
+        let placeholder = attribute_values.keys().next().unwrap().clone();
+        assert_eq!(
+            key_condition,
+            format!("begins_with(#name.#first, {})", placeholder)
+        );
+        assert_eq!(attribute_names, expected_names);
+    }
+
+    #[test]
+    fn test_typed_field_key_condition() {
+        let cond = User::key_cond()
+            .id()
+            .eq("id0")
+            .and(User::key_cond().renamed().between(1usize, 3usize));
+        let (key_condition, attribute_names, attribute_values) = cond.build();
+        let mut expected_names: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        expected_names.insert("#id".to_owned(), "id".to_owned());
+        expected_names.insert("#Renamed".to_owned(), "Renamed".to_owned());
+        let mut expected_values: std::collections::HashMap<String, AttributeValue> =
+            std::collections::HashMap::new();
+        expected_values.insert(":value0".to_owned(), "id0".into_attr());
+        expected_values.insert(":value1".to_owned(), 1.into_attr());
+        expected_values.insert(":value2".to_owned(), 3.into_attr());
+        assert_eq!(
+            key_condition,
+            "#id = :value0 AND (#Renamed BETWEEN :value1 AND :value2)".to_owned(),
+        );
+        assert_eq!(attribute_names, expected_names);
+        assert_eq!(attribute_values, expected_values);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_sort_key_range() {
+        assert_eq!(
+            (1usize..3).into_sort_key_range(),
+            Some(KeyConditionTypes::Between(1.into_attr(), 2.into_attr()))
+        );
+        assert_eq!(
+            (1usize..=3).into_sort_key_range(),
+            Some(KeyConditionTypes::Between(1.into_attr(), 3.into_attr()))
+        );
+        assert_eq!(
+            (..3usize).into_sort_key_range(),
+            Some(KeyConditionTypes::Lt(3.into_attr()))
+        );
+        assert_eq!(
+            (..=3usize).into_sort_key_range(),
+            Some(KeyConditionTypes::Le(3.into_attr()))
+        );
+        assert_eq!(
+            (1usize..).into_sort_key_range(),
+            Some(KeyConditionTypes::Ge(1.into_attr()))
+        );
+        // Empty and reversed ranges, which `BETWEEN` can not express.
+        assert_eq!((3usize..3).into_sort_key_range(), None);
+        assert_eq!((1usize..0).into_sort_key_range(), None);
+        assert_eq!(
+            (3usize..=3).into_sort_key_range(),
+            Some(KeyConditionTypes::Between(3.into_attr(), 3.into_attr()))
+        );
+        assert_eq!((5usize..=3).into_sort_key_range(), None);
+        assert_eq!((0.5..=f64::NAN).into_sort_key_range(), None);
+        assert_eq!(
+            IntoSortKeyRange::<String>::into_sort_key_range("b"..="a"),
+            None
+        );
+        assert_eq!(
+            IntoSortKeyRange::<String>::into_sort_key_range("a"..="b"),
+            Some(KeyConditionTypes::Between("a".into_attr(), "b".into_attr()))
+        );
+        assert_eq!(
+            IntoSortKeyRange::<String>::into_sort_key_range(Prefix("bo")),
+            Some(KeyConditionTypes::BeginsWith("bo".into_attr()))
+        );
+        assert_eq!(
+            IntoSortKeyRange::<Binary>::into_sort_key_range(Prefix(vec![0u8])),
+            Some(KeyConditionTypes::BeginsWith(Binary(vec![0]).into_attr()))
+        );
+    }
+
+    #[test]
+    fn test_parsed_key_condition() {
+        let mut names: AttributeNames = std::collections::HashMap::new();
+        names.insert("#id".to_owned(), "id".to_owned());
+        let mut values: AttributeValues = std::collections::HashMap::new();
+        values.insert(":id".to_owned(), "id0".into_attr());
+        let cond = raiden::expression::parse_key_condition("#id = :id", &names, &values).unwrap();
+        let cond = UserKeyConditionToken::from_parsed(cond);
+        let (key_condition, attribute_names, attribute_values) = cond.build();
+        assert_eq!(key_condition, "#id = :value0".to_owned());
+        assert_eq!(attribute_names["#id"], "id".to_owned());
+        assert_eq!(attribute_values[":value0"], "id0".into_attr());
+    }
 }
@@ -1,4 +1,5 @@
 mod batch_get;
+mod batch_write;
 mod condition;
 mod delete;
 mod get;
@@ -8,5 +9,6 @@ mod query;
 mod scan;
 mod rename;
 mod rename_all;
+mod transact_get;
 mod transact_write;
 mod update;
@@ -330,6 +330,301 @@ mod tests {
         rt.block_on(example());
     }
 
+    #[test]
+    fn test_query_with_sort_key_prefix() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = QueryTestData1::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client
+                .query()
+                .partition("id0")
+                .sort_key(Prefix("j"))
+                .run()
+                .await;
+            assert_eq!(
+                res.unwrap().items,
+                vec![
+                    QueryTestData1 {
+                        id: "id0".to_owned(),
+                        name: "jack".to_owned(),
+                    },
+                    QueryTestData1 {
+                        id: "id0".to_owned(),
+                        name: "john".to_owned(),
+                    }
+                ],
+            );
+
+            let res = client
+                .query()
+                .partition("id0")
+                .sort_key(..="jack")
+                .run()
+                .await;
+            assert_eq!(
+                res.unwrap().items,
+                vec![
+                    QueryTestData1 {
+                        id: "id0".to_owned(),
+                        name: "bob".to_owned(),
+                    },
+                    QueryTestData1 {
+                        id: "id0".to_owned(),
+                        name: "jack".to_owned(),
+                    }
+                ],
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(Raiden, Debug, PartialEq)]
+    #[raiden(table_name = "QueryTestData0")]
+    pub struct QueryTestData0WithSortKey {
+        #[raiden(partition_key)]
+        id: String,
+        name: String,
+        #[raiden(sort_key)]
+        year: usize,
+        num: usize,
+    }
+
+    #[test]
+    fn test_query_with_sort_key_range() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = QueryTestData0WithSortKey::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let years =
+                |res: Result<query::QueryOutput<QueryTestData0WithSortKey>, RaidenError>| {
+                    res.unwrap()
+                        .items
+                        .iter()
+                        .map(|item| item.year)
+                        .collect::<Vec<usize>>()
+                };
+
+            let res = client
+                .query()
+                .partition("id3")
+                .sort_key(1987..2029)
+                .run()
+                .await;
+            assert_eq!(years(res), vec![1987, 2000]);
+
+            let res = client
+                .query()
+                .partition("id3")
+                .sort_key(1987..=2029)
+                .run()
+                .await;
+            assert_eq!(years(res), vec![1987, 2000, 2029]);
+
+            let res = client.query().partition("id3").sort_key(..2000).run().await;
+            assert_eq!(years(res), vec![1987]);
+
+            let res = client.query().partition("id3").sort_key(2000..).run().await;
+            assert_eq!(years(res), vec![2000, 2029]);
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_query_with_key_condition_and_partition() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = QueryTestData0WithSortKey::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let cond = || {
+                QueryTestData0WithSortKey::key_condition(QueryTestData0WithSortKey::id()).eq("id3")
+            };
+            let res = client
+                .query()
+                .key_condition(cond())
+                .partition("id3")
+                .run()
+                .await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+
+            let res = client
+                .query()
+                .key_condition(cond())
+                .sort_key(2000..)
+                .run()
+                .await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_query_key_condition_attribute_names() {
+        let client = QueryTestData0::client(Region::Custom {
+            endpoint: "http://localhost:8000".into(),
+            name: "ap-northeast-1".into(),
+        });
+        let mut names: AttributeNames = std::collections::HashMap::new();
+        names.insert("#i".to_owned(), "user-id".to_owned());
+        let mut values: AttributeValues = std::collections::HashMap::new();
+        values.insert(":v".to_owned(), "id0".into_attr());
+        let cond = raiden::expression::parse_key_condition("#i = :v", &names, &values).unwrap();
+        let builder = client
+            .query()
+            .key_condition(QueryTestData0KeyConditionToken::from_parsed(cond));
+        assert_eq!(
+            builder.input.key_condition_expression,
+            Some("#_user_2did = :value0".to_owned())
+        );
+        // The names of the key condition are sent with those of the projection.
+        let names = builder.input.expression_attribute_names.unwrap();
+        assert_eq!(names["#_user_2did"], "user-id".to_owned());
+        assert_eq!(names["#id"], "id".to_owned());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_query_with_empty_sort_key_range() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = QueryTestData0WithSortKey::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client
+                .query()
+                .partition("id3")
+                .sort_key(2000..1987)
+                .run()
+                .await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+
+            let res = client
+                .query()
+                .partition("id3")
+                .sort_key(2000..=1987)
+                .run()
+                .await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(Raiden, Debug, PartialEq)]
+    #[raiden(table_name = "BinaryTestData0")]
+    pub struct BinaryTestData0 {
+        #[raiden(partition_key)]
+        id: Binary,
+        #[raiden(sort_key)]
+        chunk: Binary,
+    }
+
+    #[test]
+    fn test_query_with_binary_sort_key() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = BinaryTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let chunks = |res: Result<query::QueryOutput<BinaryTestData0>, RaidenError>| {
+                res.unwrap()
+                    .items
+                    .into_iter()
+                    .map(|item| item.chunk.into_inner())
+                    .collect::<Vec<Vec<u8>>>()
+            };
+
+            let res = client
+                .query()
+                .partition(vec![1])
+                .sort_key(Prefix(vec![0]))
+                .run()
+                .await;
+            assert_eq!(chunks(res), vec![vec![0, 1], vec![0, 2]]);
+
+            let res = client
+                .query()
+                .partition(vec![1])
+                .sort_key(Binary(vec![0, 2])..)
+                .run()
+                .await;
+            assert_eq!(chunks(res), vec![vec![0, 2], vec![1, 0]]);
+
+            let cond = BinaryTestData0::key_cond()
+                .id()
+                .eq(vec![1])
+                .and(BinaryTestData0::key_cond().chunk().begins_with(vec![1]));
+            let res = client.query().key_condition(cond).run().await;
+            assert_eq!(chunks(res), vec![vec![1, 0]]);
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(Raiden, Debug, PartialEq)]
+    #[raiden(table_name = "TimeTestData0")]
+    #[raiden(rename_all = "camelCase")]
+    pub struct TimeTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        #[raiden(sort_key)]
+        #[raiden(time_format = "epoch_seconds")]
+        created_at: std::time::SystemTime,
+        updated_at: std::time::SystemTime,
+        #[raiden(time_format = "epoch_seconds")]
+        expires_at: Option<std::time::SystemTime>,
+    }
+
+    #[test]
+    fn test_query_with_time_sort_key() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = TimeTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let at = |secs: u64| std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+
+            let res = client
+                .query()
+                .partition("id0")
+                .sort_key(at(1_600_000_100)..)
+                .run()
+                .await;
+            assert_eq!(
+                res.unwrap().items,
+                vec![
+                    TimeTestData0 {
+                        id: "id0".to_owned(),
+                        created_at: at(1_600_000_100),
+                        updated_at: at(1_600_000_100),
+                        expires_at: None,
+                    },
+                    TimeTestData0 {
+                        id: "id0".to_owned(),
+                        created_at: at(1_600_000_200),
+                        updated_at: at(1_600_000_200) + std::time::Duration::from_millis(500),
+                        expires_at: None,
+                    },
+                ],
+            );
+
+            let cond = TimeTestData0::key_cond()
+                .id()
+                .eq("id0")
+                .and(TimeTestData0::key_cond().created_at().lt(at(1_600_000_100)));
+            let res = client.query().key_condition(cond).run().await;
+            assert_eq!(res.unwrap().items.len(), 1);
+        }
+        rt.block_on(example());
+    }
+
     #[derive(Raiden, Debug, PartialEq)]
     #[raiden(table_name = "QueryLargeDataTest")]
     pub struct QueryLargeDataTest {
@@ -396,4 +691,136 @@ mod tests {
         }
         rt.block_on(example());
     }
+
+    #[test]
+    fn test_query_with_filter() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = QueryTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let cond = QueryTestData0::key_condition(QueryTestData0::id()).eq("id3");
+            let filter = QueryTestData0::condition()
+                .attr(QueryTestData0::name())
+                .eq_value("bar1");
+            let res = client
+                .query()
+                .key_condition(cond)
+                .filter(filter)
+                .run()
+                .await;
+
+            assert_eq!(
+                res.unwrap(),
+                query::QueryOutput {
+                    consumed_capacity: None,
+                    count: Some(1),
+                    items: vec![QueryTestData0 {
+                        id: "id3".to_owned(),
+                        name: "bar1".to_owned(),
+                        year: 2000,
+                        num: 4000,
+                    }],
+                    next_token: None,
+                    scanned_count: Some(3),
+                }
+            )
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_query_stream() {
+        use futures::TryStreamExt;
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = QueryTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let cond = QueryTestData0::key_condition(QueryTestData0::id()).eq("id0");
+            let items: Vec<QueryTestData0> = client
+                .query()
+                .key_condition(cond)
+                .stream()
+                .try_collect()
+                .await
+                .unwrap();
+
+            assert_eq!(
+                items,
+                vec![
+                    QueryTestData0 {
+                        id: "id0".to_owned(),
+                        name: "john".to_owned(),
+                        year: 1999,
+                        num: 1000,
+                    },
+                    QueryTestData0 {
+                        id: "id0".to_owned(),
+                        name: "john".to_owned(),
+                        year: 2000,
+                        num: 2000,
+                    },
+                ]
+            )
+        }
+        rt.block_on(example());
+    }
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RETRY_COUNT: AtomicUsize = AtomicUsize::new(1);
+    struct MyRetryStrategy;
+
+    impl RetryStrategy for MyRetryStrategy {
+        fn should_retry(&self, _error: &RaidenError, retry_count: usize) -> bool {
+            RETRY_COUNT.store(retry_count, Ordering::Relaxed);
+            true
+        }
+
+        fn policy(&self) -> Policy {
+            Policy::Limit(3)
+        }
+    }
+
+    #[derive(Raiden, Debug, PartialEq)]
+    #[raiden(table_name = "QueryRetryNotExistTable")]
+    pub struct QueryRetryTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        name: String,
+    }
+
+    // Builder methods are called from another module to make sure they are public.
+    mod other {
+        use super::*;
+
+        pub async fn query_with_retries(
+        ) -> Result<query::QueryOutput<QueryRetryTestData0>, RaidenError> {
+            let client = QueryRetryTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            })
+            .with_retries(Box::new(MyRetryStrategy));
+            let cond = QueryRetryTestData0::key_condition(QueryRetryTestData0::id()).eq("id0");
+            client
+                .query()
+                .key_condition(cond)
+                .consistent()
+                .limit(1)
+                .run()
+                .await
+        }
+    }
+
+    #[test]
+    fn test_query_retry() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let res = rt.block_on(other::query_with_retries());
+        assert!(res.is_err());
+        assert_eq!(RETRY_COUNT.load(Ordering::Relaxed), 3)
+    }
 }
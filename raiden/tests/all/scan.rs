@@ -189,4 +189,137 @@ mod tests {
         }
         rt.block_on(example());
     }
+
+    #[test]
+    fn test_scan_with_filter() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = ScanTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let filter = ScanTestData0::condition()
+                .attr(ScanTestData0::name())
+                .eq_value("scanBob");
+            let res = client.scan().filter(filter).run().await;
+
+            assert_eq!(
+                res.unwrap(),
+                scan::ScanOutput {
+                    consumed_capacity: None,
+                    count: Some(0),
+                    items: vec![],
+                    last_evaluated_key: None,
+                    scanned_count: Some(1),
+                }
+            )
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_parallel_scan() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = Test::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.scan().parallel(4).run().await.unwrap();
+            let mut ids: Vec<String> = res.items.into_iter().map(|item| item.id).collect();
+            ids.sort();
+            let mut expected: Vec<String> = (0..10).map(|n| format!("id{}", n)).collect();
+            expected.sort();
+            assert_eq!(ids, expected);
+            assert_eq!(res.count, Some(10));
+
+            let res = client
+                .scan()
+                .parallel(4)
+                .concurrency(2)
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(res.count, Some(10));
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_parallel_scan_validation() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = Test::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.scan().parallel(0).run().await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+            let res = client.scan().parallel(1_000_001).run().await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+            let res = client.scan().parallel(4).concurrency(0).run().await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+            let res = client
+                .scan()
+                .parallel(4)
+                .next_token(NextToken::new("eyJpZCI6eyJTIjoiaWQwIn19"))
+                .run()
+                .await;
+            assert!(matches!(res, Err(RaidenError::Validation(_))));
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_scan_segment() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = Test::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let mut total = 0;
+            for segment in 0..3 {
+                let res = client.scan().segment(segment, 3).run().await.unwrap();
+                total += res.items.len();
+            }
+            assert_eq!(total, 10);
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_scan_pages() {
+        use futures::TryStreamExt;
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = Test::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let pages: Vec<scan::ScanOutput<Test>> =
+                client.scan().pages().try_collect().await.unwrap();
+            assert!(pages.len() > 1);
+            assert_eq!(pages.iter().map(|page| page.items.len()).sum::<usize>(), 10);
+            assert_eq!(pages.last().unwrap().last_evaluated_key, None);
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_scan_stream_with_limit() {
+        use futures::TryStreamExt;
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = Test::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let items: Vec<Test> = client.scan().limit(5).stream().try_collect().await.unwrap();
+            assert_eq!(items.len(), 5);
+        }
+        rt.block_on(example());
+    }
 }
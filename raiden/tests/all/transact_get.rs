@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use raiden::*;
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "user")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct User {
+        #[raiden(partition_key)]
+        id: String,
+        name: String,
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "BatchTest1")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Book {
+        #[raiden(partition_key)]
+        id: String,
+        #[raiden(sort_key)]
+        year: usize,
+        name: String,
+        num: usize,
+    }
+
+    #[test]
+    fn test_transact_get_from_multiple_tables() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let tx = ::raiden::ReadTx::new(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = tx
+                .get(User::transact_get("user_primary_key"))
+                .get(Book::transact_get("id1", 2001_usize))
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.get::<User>(0).unwrap(),
+                Some(User {
+                    id: "user_primary_key".to_owned(),
+                    name: "bokuweb".to_owned(),
+                })
+            );
+            assert_eq!(
+                res.get::<Book>(1).unwrap(),
+                Some(Book {
+                    id: "id1".to_owned(),
+                    year: 2001,
+                    name: "bob".to_owned(),
+                    num: 1,
+                })
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_transact_get_missing_item() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let tx = ::raiden::ReadTx::new(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = tx
+                .get(User::transact_get("transact_get_missing_key"))
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(res.get::<User>(0).unwrap(), None);
+            assert!(matches!(
+                res.get::<User>(1),
+                Err(RaidenError::Validation(_))
+            ));
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_transact_get_with_prefix_suffix() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let tx = ::raiden::ReadTx::new(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = tx
+                .get(
+                    User::transact_get("user_primary_key")
+                        .table_prefix("test-")
+                        .table_suffix("-staging"),
+                )
+                .run()
+                .await;
+            assert_eq!(res.is_ok(), true);
+        }
+        rt.block_on(example());
+    }
+}
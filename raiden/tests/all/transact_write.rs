@@ -271,4 +271,57 @@ mod tests {
         }
         rt.block_on(example());
     }
+
+    #[derive(Raiden, Debug, Clone, PartialEq)]
+    pub struct TxSortKeyTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        #[raiden(sort_key)]
+        year: usize,
+        name: String,
+    }
+
+    #[test]
+    fn test_transact_write_with_sort_key() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let tx = ::raiden::WriteTx::new(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let set_expression = TxSortKeyTestData0::update_expression()
+                .set(TxSortKeyTestData0::name())
+                .value("updated!!");
+            let cond = TxSortKeyTestData0::condition().attr_exists(TxSortKeyTestData0::id());
+            let res = tx
+                .delete(TxSortKeyTestData0::delete("id0", 2000_usize))
+                .update(TxSortKeyTestData0::update("id1", 2001_usize).set(set_expression))
+                .condition_check(
+                    TxSortKeyTestData0::condition_check("id2", 2002_usize).condition(cond),
+                )
+                .run()
+                .await;
+            assert_eq!(res.is_ok(), true);
+
+            let client = TxSortKeyTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client.get("id0", 2000_usize).run().await;
+            assert_eq!(
+                res.unwrap_err(),
+                RaidenError::ResourceNotFound("resource not found".to_owned())
+            );
+            let res = client.get("id1", 2001_usize).run().await;
+            assert_eq!(
+                res.unwrap().item,
+                TxSortKeyTestData0 {
+                    id: "id1".to_owned(),
+                    year: 2001,
+                    name: "updated!!".to_owned()
+                }
+            );
+        }
+        rt.block_on(example());
+    }
 }
@@ -458,4 +458,237 @@ mod tests {
         }
         rt.block_on(example());
     }
+
+    #[derive(Raiden, Debug, Clone, PartialEq)]
+    pub struct UpdateArithmeticTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        count: usize,
+        logs: Vec<String>,
+    }
+
+    #[test]
+    fn test_update_with_sub_and_list_append() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = UpdateArithmeticTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let count_expression = UpdateArithmeticTestData0::update_expression()
+                .set(UpdateArithmeticTestData0::count())
+                .attr(UpdateArithmeticTestData0::count())
+                .sub_value(1);
+            let logs_expression = UpdateArithmeticTestData0::update_expression()
+                .set(UpdateArithmeticTestData0::logs())
+                .attr(UpdateArithmeticTestData0::logs())
+                .list_append_value(vec!["b"]);
+            let res = client
+                .update("id0")
+                .set(count_expression)
+                .set(logs_expression)
+                .return_all_new()
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.item,
+                Some(UpdateArithmeticTestData0 {
+                    id: "id0".to_owned(),
+                    count: 0,
+                    logs: vec!["a".to_owned(), "b".to_owned()],
+                })
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_update_with_if_not_exists_in_arithmetic() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = UpdateArithmeticTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let count_expression = UpdateArithmeticTestData0::update_expression()
+                .set(UpdateArithmeticTestData0::count())
+                .value(0)
+                .if_not_exists()
+                .add_value(1);
+            let logs_expression = UpdateArithmeticTestData0::update_expression()
+                .set(UpdateArithmeticTestData0::logs())
+                .value(vec!["init"])
+                .if_not_exists()
+                .list_prepend_value(vec!["x"]);
+            let res = client
+                .update("id1")
+                .set(count_expression)
+                .set(logs_expression)
+                .return_all_new()
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.item,
+                Some(UpdateArithmeticTestData0 {
+                    id: "id1".to_owned(),
+                    count: 1,
+                    logs: vec!["x".to_owned(), "init".to_owned()],
+                })
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_update_with_if_not_exists_at_other_path() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = UpdateArithmeticTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            // `count` exists but `logs` does not, so the count starts over from 0.
+            let count_expression = UpdateArithmeticTestData0::update_expression()
+                .set(UpdateArithmeticTestData0::count())
+                .value(0)
+                .if_not_exists_at(UpdateArithmeticTestData0::logs())
+                .add_value(1);
+            let logs_expression = UpdateArithmeticTestData0::update_expression()
+                .set(UpdateArithmeticTestData0::logs())
+                .value(vec!["x"]);
+            let res = client
+                .update("id5")
+                .set(count_expression)
+                .set(logs_expression)
+                .return_all_new()
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.item,
+                Some(UpdateArithmeticTestData0 {
+                    id: "id5".to_owned(),
+                    count: 1,
+                    logs: vec!["x".to_owned()],
+                })
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_update_with_list_index() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = UpdateArithmeticTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let set_expression = UpdateArithmeticTestData0::update_expression()
+                .set(UpdateArithmeticTestData0::logs())
+                .index(0)
+                .value("z");
+            let res = client
+                .update("id2")
+                .set(set_expression)
+                .remove_indexes(UpdateArithmeticTestData0::logs(), vec![3, 1])
+                .return_all_new()
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.item,
+                Some(UpdateArithmeticTestData0 {
+                    id: "id2".to_owned(),
+                    count: 0,
+                    logs: vec!["z".to_owned(), "c".to_owned()],
+                })
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[test]
+    fn test_update_with_remove_index() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = UpdateArithmeticTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let res = client
+                .update("id3")
+                .remove_index(UpdateArithmeticTestData0::logs(), 0)
+                .return_all_new()
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.item,
+                Some(UpdateArithmeticTestData0 {
+                    id: "id3".to_owned(),
+                    count: 0,
+                    logs: vec!["b".to_owned()],
+                })
+            );
+        }
+        rt.block_on(example());
+    }
+
+    #[derive(Raiden)]
+    #[raiden(table_name = "UpdateArithmeticTestData0")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UpdateFieldNameTestData0 {
+        #[raiden(partition_key)]
+        id: String,
+        set: Vec<String>,
+        add: usize,
+    }
+
+    #[test]
+    fn test_typed_field_expression_named_like_operation() {
+        let expression = UpdateFieldNameTestData0::update_expression();
+        // The untyped `add` and the typed `add_field` are both available.
+        let _ = expression.add(UpdateFieldNameTestData0::add()).value(1);
+        let _ = expression.add_field().add(1usize);
+        let _ = expression.set_field().set(vec!["x".to_owned()]);
+    }
+
+    #[test]
+    fn test_update_with_typed_field_expression() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        async fn example() {
+            let client = UpdateArithmeticTestData0::client(Region::Custom {
+                endpoint: "http://localhost:8000".into(),
+                name: "ap-northeast-1".into(),
+            });
+            let add_expression = UpdateArithmeticTestData0::update_expression()
+                .count()
+                .add(2usize);
+            let set_expression = UpdateArithmeticTestData0::update_expression()
+                .logs()
+                .set(vec!["x".to_owned()]);
+            let cond = UpdateArithmeticTestData0::cond().count().lt(10usize);
+            let res = client
+                .update("id4")
+                .add(add_expression)
+                .set(set_expression)
+                .condition(cond)
+                .return_all_new()
+                .run()
+                .await
+                .unwrap();
+            assert_eq!(
+                res.item,
+                Some(UpdateArithmeticTestData0 {
+                    id: "id4".to_owned(),
+                    count: 3,
+                    logs: vec!["x".to_owned()],
+                })
+            );
+        }
+        rt.block_on(example());
+    }
 }
@@ -66,6 +66,180 @@ const put = (params) =>
     },
   });
 
+  await createTable({
+    TableName: 'NestedTestData0',
+    KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
+    AttributeDefinitions: [{ AttributeName: 'id', AttributeType: 'S' }],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  await put({
+    TableName: 'NestedTestData0',
+    Item: {
+      id: { S: 'id0' },
+      address: {
+        M: {
+          city: { S: 'Tokyo' },
+          geo: { M: { lat: { N: '35.68' }, lng: { N: '139.76' } } },
+        },
+      },
+      settings: { M: { darkMode: { BOOL: true }, lang: { S: 'ja' } } },
+      past_addresses: {
+        L: [
+          {
+            M: {
+              city: { S: 'Osaka' },
+              geo: { M: { lat: { N: '34.69' }, lng: { N: '135.5' } } },
+            },
+          },
+        ],
+      },
+    },
+  });
+
+  await createTable({
+    TableName: 'EnumTestData0',
+    KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
+    AttributeDefinitions: [{ AttributeName: 'id', AttributeType: 'S' }],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  await put({
+    TableName: 'EnumTestData0',
+    Item: {
+      id: { S: 'id0' },
+      status: { S: 'in_progress' },
+      shape: {
+        M: {
+          kind: { S: 'Rect' },
+          data: { M: { width: { N: '2' }, height: { N: '3' } } },
+        },
+      },
+      history: {
+        L: [
+          { M: { kind: { S: 'Point' } } },
+          { M: { kind: { S: 'Circle' }, data: { N: '1.5' } } },
+          { M: { kind: { S: 'Line' }, data: { L: [{ N: '1' }, { N: '2' }] } } },
+        ],
+      },
+    },
+  });
+
+  await createTable({
+    TableName: 'MapTestData0',
+    KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
+    AttributeDefinitions: [{ AttributeName: 'id', AttributeType: 'S' }],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  await put({
+    TableName: 'MapTestData0',
+    Item: {
+      id: { S: 'id0' },
+      scores: { M: { alice: { N: '10' }, bob: { N: '20' } } },
+      labels: { M: { env: { S: 'prod' }, team: { S: 'core' } } },
+      empty: { NULL: true },
+      metadata: {
+        M: {
+          version: { N: '2' },
+          ratio: { N: '0.5' },
+          tags: { L: [{ S: 'a' }, { L: [{ N: '1' }, { BOOL: true }] }] },
+          owner: { NULL: true },
+        },
+      },
+    },
+  });
+
+  await createTable({
+    TableName: 'BinaryTestData0',
+    KeySchema: [
+      { AttributeName: 'id', KeyType: 'HASH' },
+      { AttributeName: 'chunk', KeyType: 'RANGE' },
+    ],
+    AttributeDefinitions: [
+      { AttributeName: 'id', AttributeType: 'B' },
+      { AttributeName: 'chunk', AttributeType: 'B' },
+    ],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  await put({
+    TableName: 'BinaryTestData0',
+    Item: {
+      id: { B: Buffer.from([1]) },
+      chunk: { B: Buffer.from([0, 1]) },
+      data: { B: Buffer.from('hello') },
+      hashes: { BS: [Buffer.from([0xde, 0xad]), Buffer.from([0xbe, 0xef])] },
+    },
+  });
+
+  await put({
+    TableName: 'BinaryTestData0',
+    Item: {
+      id: { B: Buffer.from([1]) },
+      chunk: { B: Buffer.from([0, 2]) },
+      data: { B: Buffer.from('world') },
+    },
+  });
+
+  await put({
+    TableName: 'BinaryTestData0',
+    Item: {
+      id: { B: Buffer.from([1]) },
+      chunk: { B: Buffer.from([1, 0]) },
+      data: { B: Buffer.from('!') },
+    },
+  });
+
+  await createTable({
+    TableName: 'TimeTestData0',
+    KeySchema: [
+      { AttributeName: 'id', KeyType: 'HASH' },
+      { AttributeName: 'createdAt', KeyType: 'RANGE' },
+    ],
+    AttributeDefinitions: [
+      { AttributeName: 'id', AttributeType: 'S' },
+      { AttributeName: 'createdAt', AttributeType: 'N' },
+    ],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  for (const [createdAt, updatedAt] of [
+    ['1600000000', '2020-09-13T12:26:40.000000000Z'],
+    ['1600000100', '2020-09-13T12:28:20.000000000Z'],
+    ['1600000200', '2020-09-13T12:30:00.500000000Z'],
+  ]) {
+    await put({
+      TableName: 'TimeTestData0',
+      Item: {
+        id: { S: 'id0' },
+        createdAt: { N: createdAt },
+        updatedAt: { S: updatedAt },
+      },
+    });
+  }
+
+  await createTable({
+    TableName: 'NumberTestData0',
+    KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
+    AttributeDefinitions: [{ AttributeName: 'id', AttributeType: 'S' }],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  await put({
+    TableName: 'NumberTestData0',
+    Item: {
+      id: { S: 'id0' },
+      wide: { N: '12345678901234567890123456789012345678' },
+      amount: { N: '1234567890.123456789' },
+    },
+  });
+
+  await put({
+    TableName: 'NumberTestData0',
+    Item: { id: { S: 'id1' }, wide: { N: '1.5' }, amount: { N: '0' } },
+  });
+
   await createTable({
     TableName: 'QueryTestData0',
     KeySchema: [
@@ -350,6 +524,33 @@ const put = (params) =>
     });
   }
 
+  await createTable({
+    TableName: 'BatchWriteTest0',
+    KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
+    AttributeDefinitions: [{ AttributeName: 'id', AttributeType: 'S' }],
+    ProvisionedThroughput: { ReadCapacityUnits: 50, WriteCapacityUnits: 50 },
+  });
+
+  await createTable({
+    TableName: 'BatchWriteTest1',
+    KeySchema: [
+      { AttributeName: 'id', KeyType: 'HASH' },
+      { AttributeName: 'year', KeyType: 'RANGE' },
+    ],
+    AttributeDefinitions: [
+      { AttributeName: 'id', AttributeType: 'S' },
+      { AttributeName: 'year', AttributeType: 'N' },
+    ],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  for (let i = 0; i < 2; i++) {
+    await put({
+      TableName: 'BatchWriteTest1',
+      Item: { id: { S: `id${i}` }, name: { S: 'bob' }, year: { N: `${2000 + i}` } },
+    });
+  }
+
   await createTable({
     TableName: 'test-user-staging',
     KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
@@ -602,6 +803,26 @@ const put = (params) =>
     Item: { id: { S: 'id1' }, name: { S: 'world' } },
   });
 
+  await createTable({
+    TableName: 'TxSortKeyTestData0',
+    KeySchema: [
+      { AttributeName: 'id', KeyType: 'HASH' },
+      { AttributeName: 'year', KeyType: 'RANGE' },
+    ],
+    AttributeDefinitions: [
+      { AttributeName: 'id', AttributeType: 'S' },
+      { AttributeName: 'year', AttributeType: 'N' },
+    ],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  for (let i = 0; i < 3; i++) {
+    await put({
+      TableName: 'TxSortKeyTestData0',
+      Item: { id: { S: `id${i}` }, name: { S: 'hello' }, year: { N: `${2000 + i}` } },
+    });
+  }
+
   await createTable({
     TableName: 'UpdateRemoveTestData0',
     KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
@@ -635,6 +856,59 @@ const put = (params) =>
     },
   });
 
+  await createTable({
+    TableName: 'UpdateArithmeticTestData0',
+    KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],
+    AttributeDefinitions: [{ AttributeName: 'id', AttributeType: 'S' }],
+    ProvisionedThroughput: { ReadCapacityUnits: 5, WriteCapacityUnits: 5 },
+  });
+
+  await put({
+    TableName: 'UpdateArithmeticTestData0',
+    Item: {
+      id: { S: 'id0' },
+      count: { N: '1' },
+      logs: { L: [{ S: 'a' }] },
+    },
+  });
+
+  await put({
+    TableName: 'UpdateArithmeticTestData0',
+    Item: { id: { S: 'id1' } },
+  });
+
+  await put({
+    TableName: 'UpdateArithmeticTestData0',
+    Item: {
+      id: { S: 'id2' },
+      count: { N: '0' },
+      logs: { L: [{ S: 'a' }, { S: 'b' }, { S: 'c' }, { S: 'd' }] },
+    },
+  });
+
+  await put({
+    TableName: 'UpdateArithmeticTestData0',
+    Item: {
+      id: { S: 'id3' },
+      count: { N: '0' },
+      logs: { L: [{ S: 'a' }, { S: 'b' }] },
+    },
+  });
+
+  await put({
+    TableName: 'UpdateArithmeticTestData0',
+    Item: {
+      id: { S: 'id4' },
+      count: { N: '1' },
+      logs: { L: [] },
+    },
+  });
+
+  await put({
+    TableName: 'UpdateArithmeticTestData0',
+    Item: { id: { S: 'id5' }, count: { N: '5' } },
+  });
+
   await createTable({
     TableName: 'QueryLargeDataTest',
     KeySchema: [{ AttributeName: 'id', KeyType: 'HASH' }],